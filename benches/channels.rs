@@ -0,0 +1,139 @@
+use std::hint::black_box;
+use std::sync::mpsc;
+use std::thread;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use some_rust_examples::ch::{chennel_only_channel, mpsc_intrusive, tx_rx_channel, ChannelReceiver, ChannelSender};
+
+const ROUND_TRIPS: u64 = 1_000;
+const MSGS_PER_PRODUCER: u64 = 10_000;
+
+// std::sync::mpsc brought under the common channel interface, as the baseline for the channels of
+// this crate to be held against
+#[derive(Clone)]
+struct StdSender(mpsc::Sender<u64>);
+
+impl ChannelSender<u64> for StdSender {
+    fn send(&self, value: u64) -> Result<(), u64> {
+        self.0.send(value).map_err(|err| err.0)
+    }
+}
+
+struct StdReceiver(mpsc::Receiver<u64>);
+
+impl ChannelReceiver<u64> for StdReceiver {
+    fn recv(&self) -> Option<u64> {
+        self.0.recv().ok()
+    }
+}
+
+fn std_channel() -> (StdSender, StdReceiver) {
+    let (tx, rx) = mpsc::channel();
+    (StdSender(tx), StdReceiver(rx))
+}
+
+// bounces ROUND_TRIPS msgs back and forth between the current thread and an echoing one, s.t. every
+// msg finds the receiver on the other end blocked
+fn bounce<S, R>((ping_tx, ping_rx): (S, R), (pong_tx, pong_rx): (S, R))
+where
+    S: ChannelSender<u64> + Send,
+    R: ChannelReceiver<u64> + Send,
+{
+    thread::scope(|scope| {
+        scope.spawn(move || {
+            for _ in 0..ROUND_TRIPS {
+                let _ = pong_tx.send(ping_rx.recv().unwrap());
+            }
+        });
+        for msg in 0..ROUND_TRIPS {
+            let _ = ping_tx.send(msg);
+            assert_eq!(pong_rx.recv(), Some(msg));
+        }
+    });
+}
+
+// floods the current thread with MSGS_PER_PRODUCER msgs from each of producer_cnt threads, the
+// receiving end counting them in rather than waiting on the channel to close, which not all the
+// channels here do on the drop of the senders
+fn flood<S, R>((tx, rx): (S, R), producer_cnt: u64)
+where
+    S: ChannelSender<u64> + Clone + Send,
+    R: ChannelReceiver<u64>,
+{
+    thread::scope(|scope| {
+        for _ in 0..producer_cnt {
+            let tx = tx.clone();
+            scope.spawn(move || {
+                for msg in 0..MSGS_PER_PRODUCER {
+                    let _ = tx.send(msg);
+                }
+            });
+        }
+        for _ in 0..MSGS_PER_PRODUCER * producer_cnt {
+            black_box(rx.recv());
+        }
+    });
+}
+
+/// latency of handing a msg over to a blocked receiver, for the regression baseline of each channel
+fn ping_pong(c: &mut Criterion) {
+    let mut group = c.benchmark_group("channels_ping_pong");
+    group.throughput(Throughput::Elements(ROUND_TRIPS));
+
+    group.bench_function("chennel_only_channel", |b| {
+        b.iter(|| {
+            let (ping, pong) = (chennel_only_channel::Channel::new(), chennel_only_channel::Channel::new());
+            bounce(ping.split(), pong.split());
+        })
+    });
+
+    group.bench_function("tx_rx_channel", |b| {
+        b.iter(|| bounce(tx_rx_channel::channel(), tx_rx_channel::channel()))
+    });
+
+    group.bench_function("mpsc_intrusive", |b| {
+        b.iter(|| bounce(mpsc_intrusive::channel(), mpsc_intrusive::channel()))
+    });
+
+    group.bench_function("std_mpsc", |b| {
+        b.iter(|| bounce(std_channel(), std_channel()))
+    });
+
+    group.finish();
+}
+
+/// throughput of one consumer thread against a growing number of producer threads, one producer
+/// being the spsc case
+fn throughput(c: &mut Criterion) {
+    let mut group = c.benchmark_group("channels_throughput");
+
+    for producer_cnt in [1, 4, 16] {
+        group.throughput(Throughput::Elements(MSGS_PER_PRODUCER * producer_cnt));
+
+        group.bench_with_input(BenchmarkId::new("chennel_only_channel", producer_cnt), &producer_cnt, |b, &producer_cnt| {
+            b.iter(|| {
+                let channel = chennel_only_channel::Channel::new();
+                flood(channel.split(), producer_cnt);
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("tx_rx_channel", producer_cnt), &producer_cnt, |b, &producer_cnt| {
+            b.iter(|| flood(tx_rx_channel::channel(), producer_cnt))
+        });
+
+        // the lock-free send path against the mutex of tx_rx_channel, which is where the two part
+        // ways the more producers contend
+        group.bench_with_input(BenchmarkId::new("mpsc_intrusive", producer_cnt), &producer_cnt, |b, &producer_cnt| {
+            b.iter(|| flood(mpsc_intrusive::channel(), producer_cnt))
+        });
+
+        group.bench_with_input(BenchmarkId::new("std_mpsc", producer_cnt), &producer_cnt, |b, &producer_cnt| {
+            b.iter(|| flood(std_channel(), producer_cnt))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, ping_pong, throughput);
+criterion_main!(benches);
@@ -0,0 +1,125 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use some_rust_examples::arena_linked_list::LinkedList as ArenaLinkedList;
+use some_rust_examples::mut_single_linked_list::{LinkedList, LinkedListBadLayout};
+
+const SIZES: [i32; 3] = [100, 10_000, 100_000];
+
+fn build_bad_layout(n: i32) -> LinkedListBadLayout {
+    let mut list = LinkedListBadLayout::new();
+    for i in 0..n {
+        list.push_front(i);
+    }
+    list
+}
+
+fn sum_bad_layout(list: &LinkedListBadLayout) -> i64 {
+    let mut sum = 0;
+    let mut cur = list;
+    while let LinkedListBadLayout::Cons(data, next) = cur {
+        sum += *data as i64;
+        cur = next;
+    }
+    sum
+}
+
+fn build_linked_list(n: i32) -> LinkedList<i32> {
+    let mut list = LinkedList::new();
+    for i in 0..n {
+        list.push_front(i);
+    }
+    list
+}
+
+fn build_arena_linked_list(n: i32) -> ArenaLinkedList<i32> {
+    let mut list = ArenaLinkedList::new();
+    for i in 0..n {
+        list.push_front(i);
+    }
+    list
+}
+
+/// iteration throughput over the same elements stored in the four layouts, the gap between the
+/// pointer lists and Vec being the cost of chasing one heap pointer per element, which the arena list
+/// chases within the one Vec its nodes are packed into
+fn iterate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iterate");
+    for n in SIZES {
+        let bad_layout = build_bad_layout(n);
+        let linked_list = build_linked_list(n);
+        let arena_linked_list = build_arena_linked_list(n);
+        let vec: Vec<i32> = (0..n).collect();
+
+        group.bench_with_input(BenchmarkId::new("LinkedListBadLayout", n), &bad_layout, |b, l| {
+            b.iter(|| sum_bad_layout(black_box(l)))
+        });
+        group.bench_with_input(BenchmarkId::new("LinkedList", n), &linked_list, |b, l| {
+            b.iter(|| black_box(l).iter().map(|&x| x as i64).sum::<i64>())
+        });
+        group.bench_with_input(BenchmarkId::new("ArenaLinkedList", n), &arena_linked_list, |b, l| {
+            b.iter(|| black_box(l).iter().map(|&x| x as i64).sum::<i64>())
+        });
+        group.bench_with_input(BenchmarkId::new("Vec", n), &vec, |b, v| {
+            b.iter(|| black_box(v).iter().map(|&x| x as i64).sum::<i64>())
+        });
+
+        // the bad layout is dropped recursively, one stack frame per node, which is liable to
+        // overflow the stack at the larger sizes, so leak it instead
+        std::mem::forget(bad_layout);
+    }
+    group.finish();
+}
+
+/// the cost of building and dropping a list, one allocation per node for the pointer list, against
+/// the handful of times the arena grows, amortized over its nodes
+fn build(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build");
+    for n in SIZES {
+        group.bench_with_input(BenchmarkId::new("LinkedList", n), &n, |b, &n| {
+            b.iter(|| build_linked_list(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("ArenaLinkedList", n), &n, |b, &n| {
+            b.iter(|| build_arena_linked_list(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("Vec", n), &n, |b, &n| {
+            b.iter(|| (0..black_box(n)).collect::<Vec<i32>>())
+        });
+    }
+    group.finish();
+}
+
+/// the same stack API run on both layouts, pushing n values and popping them all off, which moves a
+/// value in and out of a Box on every push and pop of the bad layout, and only relinks the nodes of
+/// LinkedList
+fn push_pop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_pop");
+    for n in SIZES {
+        group.bench_with_input(BenchmarkId::new("LinkedListBadLayout", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut list = LinkedListBadLayout::new();
+                for i in 0..black_box(n) {
+                    list.push_front(i);
+                }
+                while let Some(value) = list.pop_front() {
+                    black_box(value);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("LinkedList", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut list = LinkedList::new();
+                for i in 0..black_box(n) {
+                    list.push_front(i);
+                }
+                while let Some(value) = list.pop_front() {
+                    black_box(value);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, iterate, build, push_pop);
+criterion_main!(benches);
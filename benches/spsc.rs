@@ -0,0 +1,101 @@
+use std::hint::black_box;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use some_rust_examples::ch::cache_padded::CachePadded;
+use some_rust_examples::ch::{spsc, tx_rx_channel};
+
+const MSGS: u64 = 100_000;
+const CAPACITY: usize = 1024;
+
+/// throughput of one producer thread handing MSGS msgs over to one consumer thread through a bounded
+/// buffer of the same capacity, the lock-free ring buffer against the mutex-based channels
+fn spsc_throughput(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spsc_throughput");
+    group.throughput(Throughput::Elements(MSGS));
+
+    group.bench_function("spsc", |b| {
+        b.iter(|| {
+            let (mut tx, mut rx) = spsc::channel::<u64>(CAPACITY);
+            let producer = thread::spawn(move || {
+                for msg in 0..MSGS {
+                    let _ = tx.send(msg);
+                }
+            });
+            while let Ok(msg) = rx.recv() {
+                black_box(msg);
+            }
+            producer.join().unwrap();
+        })
+    });
+
+    group.bench_function("tx_rx_channel", |b| {
+        b.iter(|| {
+            let (tx, rx) = tx_rx_channel::sync_channel::<u64>(CAPACITY);
+            let producer = thread::spawn(move || {
+                for msg in 0..MSGS {
+                    let _ = tx.send(msg);
+                }
+            });
+            while let Ok(msg) = rx.recv() {
+                black_box(msg);
+            }
+            producer.join().unwrap();
+        })
+    });
+
+    group.bench_function("std_mpsc", |b| {
+        b.iter(|| {
+            let (tx, rx) = std::sync::mpsc::sync_channel::<u64>(CAPACITY);
+            let producer = thread::spawn(move || {
+                for msg in 0..MSGS {
+                    let _ = tx.send(msg);
+                }
+            });
+            while let Ok(msg) = rx.recv() {
+                black_box(msg);
+            }
+            producer.join().unwrap();
+        })
+    });
+
+    group.finish();
+}
+
+const INCREMENTS: u64 = 1_000_000;
+
+/// two threads each bumping a counter of their own, the way the producer and the consumer of the
+/// spsc ring bump their indices, with the two counters on the same cache line or on lines of their
+/// own, where the former only shows its cost with the two threads on different cores
+fn false_sharing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("false_sharing");
+    group.throughput(Throughput::Elements(2 * INCREMENTS));
+
+    fn bump_both(counters: [&AtomicU64; 2]) {
+        thread::scope(|scope| {
+            for counter in counters {
+                scope.spawn(move || {
+                    for _ in 0..INCREMENTS {
+                        counter.fetch_add(1, Ordering::Release);
+                    }
+                });
+            }
+        });
+    }
+
+    group.bench_function("adjacent", |b| {
+        let counters = [AtomicU64::new(0), AtomicU64::new(0)];
+        b.iter(|| bump_both([&counters[0], &counters[1]]))
+    });
+
+    group.bench_function("cache_padded", |b| {
+        let counters = [CachePadded(AtomicU64::new(0)), CachePadded(AtomicU64::new(0))];
+        b.iter(|| bump_both([&counters[0], &counters[1]]))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, spsc_throughput, false_sharing);
+criterion_main!(benches);
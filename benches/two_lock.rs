@@ -0,0 +1,65 @@
+use std::hint::black_box;
+use std::thread;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use some_rust_examples::ch::{two_lock, tx_rx_channel};
+
+const MSGS_PER_PRODUCER: u64 = 10_000;
+
+/// throughput of a growing number of producer threads flooding one consumer thread through an
+/// unbounded channel, the single-lock queue against the two-lock queue, where the producers only
+/// contend among themselves on the tail lock rather than with the consumer too
+fn many_producers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("many_producers");
+
+    for producer_cnt in [1, 2, 4, 8] {
+        group.throughput(Throughput::Elements(MSGS_PER_PRODUCER * producer_cnt));
+
+        group.bench_with_input(BenchmarkId::new("tx_rx_channel", producer_cnt), &producer_cnt, |b, &producer_cnt| {
+            b.iter(|| {
+                let (tx, rx) = tx_rx_channel::channel::<u64>();
+                let producers: Vec<_> = (0..producer_cnt)
+                    .map(|_| {
+                        let tx = tx.clone();
+                        thread::spawn(move || {
+                            for msg in 0..MSGS_PER_PRODUCER {
+                                let _ = tx.send(msg);
+                            }
+                        })
+                    })
+                    .collect();
+                drop(tx);
+                while let Ok(msg) = rx.recv() {
+                    black_box(msg);
+                }
+                producers.into_iter().for_each(|producer| producer.join().unwrap());
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("two_lock", producer_cnt), &producer_cnt, |b, &producer_cnt| {
+            b.iter(|| {
+                let (tx, rx) = two_lock::channel::<u64>();
+                let producers: Vec<_> = (0..producer_cnt)
+                    .map(|_| {
+                        let tx = tx.clone();
+                        thread::spawn(move || {
+                            for msg in 0..MSGS_PER_PRODUCER {
+                                let _ = tx.send(msg);
+                            }
+                        })
+                    })
+                    .collect();
+                drop(tx);
+                while let Ok(msg) = rx.recv() {
+                    black_box(msg);
+                }
+                producers.into_iter().for_each(|producer| producer.join().unwrap());
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, many_producers);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+use std::thread;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use some_rust_examples::ch::{parking, tx_rx_channel};
+
+const ROUND_TRIPS: u64 = 1_000;
+
+/// latency of waking up a blocked receiver, measured by bouncing a msg back and forth between two
+/// threads through a pair of channels, s.t. every msg finds the receiver on the other end blocked,
+/// the condvar-based channel against the parking-based one
+fn ping_pong(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ping_pong");
+    group.throughput(Throughput::Elements(ROUND_TRIPS));
+
+    group.bench_function("tx_rx_channel", |b| {
+        b.iter(|| {
+            let (ping_tx, ping_rx) = tx_rx_channel::channel::<u64>();
+            let (pong_tx, pong_rx) = tx_rx_channel::channel::<u64>();
+            let echo = thread::spawn(move || {
+                while let Ok(msg) = ping_rx.recv() {
+                    let _ = pong_tx.send(msg);
+                }
+            });
+            for msg in 0..ROUND_TRIPS {
+                let _ = ping_tx.send(msg);
+                assert_eq!(pong_rx.recv().unwrap(), msg);
+            }
+            drop(ping_tx);
+            echo.join().unwrap();
+        })
+    });
+
+    group.bench_function("parking", |b| {
+        b.iter(|| {
+            let (ping_tx, ping_rx) = parking::channel::<u64>();
+            let (pong_tx, pong_rx) = parking::channel::<u64>();
+            let echo = thread::spawn(move || {
+                while let Ok(msg) = ping_rx.recv() {
+                    let _ = pong_tx.send(msg);
+                }
+            });
+            for msg in 0..ROUND_TRIPS {
+                let _ = ping_tx.send(msg);
+                assert_eq!(pong_rx.recv().unwrap(), msg);
+            }
+            drop(ping_tx);
+            echo.join().unwrap();
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, ping_pong);
+criterion_main!(benches);
@@ -0,0 +1,11 @@
+#![no_std]
+
+#[path = "../../src/mut_single_linked_list.rs"]
+pub mod mut_single_linked_list;
+
+// the paths mirror the ones of ch, for no_std to find the error types it shares with tx_rx_channel
+// at super::error
+#[path = "../../src/ch/error.rs"]
+mod error;
+#[path = "../../src/ch/no_std.rs"]
+pub mod no_std;
@@ -0,0 +1,182 @@
+/// a singly linked list whose nodes all live in one Vec, the arena, and link to one another by their
+/// u32 index in it rather than by a pointer, which takes no allocation per node, no unsafe code, and
+/// puts the nodes next to one another in memory, rather than wherever the allocator has put each Box
+///
+/// the slot of a node popped off the list isn't removed from the Vec, which would shift the index of
+/// every slot after it, but left empty and linked into the free list, for the next node pushed to
+/// reuse, s.t. the arena only grows when the list is longer than it has ever been
+pub struct LinkedList<T> {
+    nodes: Vec<Node<T>>,
+    head: Link,
+    tail: Link,
+    /// the first of the empty slots, each of which links to the next one through its next
+    free: Link,
+    len: usize,
+}
+
+type Link = Option<u32>;
+
+struct Node<T> {
+    /// None for an empty slot in the free list
+    data: Option<T>,
+    next: Link,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        LinkedList { nodes: Vec::new(), head: None, tail: None, free: None, len: 0 }
+    }
+
+    /// a list with room in its arena for capacity nodes before it has to grow
+    pub fn with_capacity(capacity: usize) -> Self {
+        LinkedList { nodes: Vec::with_capacity(capacity), ..Self::new() }
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let new_node = self.alloc_node(value, self.head);
+        self.head = Some(new_node);
+        if self.tail.is_none() {
+            self.tail = Some(new_node);
+        }
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let new_node = self.alloc_node(value, None);
+        match self.tail {
+            Some(tail) => self.nodes[tail as usize].next = Some(new_node),
+            None => self.head = Some(new_node),
+        }
+        self.tail = Some(new_node);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        let node = &mut self.nodes[head as usize];
+        let data = node.data.take();
+        self.head = std::mem::replace(&mut node.next, self.free);
+        self.free = Some(head);
+        if self.head.is_none() {
+            self.tail = None;
+        }
+        self.len -= 1;
+        data
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.and_then(|head| self.nodes[head as usize].data.as_ref())
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.and_then(|head| self.nodes[head as usize].data.as_mut())
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.tail.and_then(|tail| self.nodes[tail as usize].data.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// the number of slots in the arena, taken by the nodes of the list or free for the next ones
+    pub fn slots(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { nodes: &self.nodes, next_item: self.head }
+    }
+
+    // the slot for a new node, the first free one if any, or a new one at the end of the arena
+    fn alloc_node(&mut self, value: T, next: Link) -> u32 {
+        self.len += 1;
+        let node = Node { data: Some(value), next };
+        match self.free {
+            Some(free) => {
+                self.free = std::mem::replace(&mut self.nodes[free as usize], node).next;
+                free
+            },
+            None => {
+                let index = u32::try_from(self.nodes.len()).expect("more nodes than a u32 can index");
+                self.nodes.push(node);
+                index
+            },
+        }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut list = LinkedList::with_capacity(iter.size_hint().0);
+        for value in iter {
+            list.push_back(value);
+        }
+        list
+    }
+}
+
+// no Drop to write: the nodes are dropped along with the Vec they live in, one after another, rather
+// than each by the one before it
+
+/// backing impl for iter, following the indices from the head on
+pub struct Iter<'a, T> {
+    nodes: &'a [Node<T>],
+    next_item: Link,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = &self.nodes[self.next_item? as usize];
+        self.next_item = node.next;
+        node.data.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_and_pop_like_the_pointer_list() {
+        let mut list = LinkedList::new();
+        assert_eq!((list.pop_front(), list.peek(), list.back()), (None, None, None));
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        *list.peek_mut().unwrap() *= 10;
+        assert_eq!((list.peek(), list.back(), list.len()), (Some(&10), Some(&3), 3));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 2, 3]);
+        assert_eq!((list.pop_front(), list.pop_front(), list.pop_front()), (Some(10), Some(2), Some(3)));
+        assert!(list.is_empty() && list.back().is_none());
+        list.push_front(4);
+        assert_eq!((list.peek(), list.back()), (Some(&4), Some(&4)));
+    }
+
+    #[test]
+    fn popped_slots_are_reused() {
+        let mut list: LinkedList<String> = ["a", "b", "c"].map(String::from).into_iter().collect();
+        assert_eq!(list.slots(), 3);
+        list.pop_front();
+        list.pop_front();
+        list.push_back(String::from("d"));
+        list.push_back(String::from("e"));
+        // the two slots freed are taken by the two nodes pushed, with no new one in the arena
+        assert_eq!(list.slots(), 3);
+        list.push_front(String::from("z"));
+        assert_eq!(list.slots(), 4);
+        assert_eq!(list.iter().map(String::as_str).collect::<Vec<_>>(), vec!["z", "c", "d", "e"]);
+    }
+}
@@ -1,76 +1,760 @@
 #![allow(dead_code, unused)]
 
+pub mod broadcast;
+pub mod cache_padded;
+pub mod combinators;
+mod error;
+pub mod event_bus;
+pub mod local_channel;
+pub mod mpsc_intrusive;
+#[cfg(feature = "no_std_channel")]
+pub mod no_std;
+pub mod oneshot;
+pub mod parking;
+pub mod priority;
+pub mod rpc;
+pub mod select;
+pub mod sharded;
+pub mod spsc;
+#[cfg(any(test, feature = "stress"))]
+pub mod stress;
+pub mod two_lock;
+pub mod watch;
+
+#[cfg(test)]
+mod fifo_harness;
+mod segmented;
+mod sync;
+
+/// the sending half of the interface common to the channel implementations in this module, for
+/// generic code to be written once against all of them. a failed send hands the value back
+pub trait ChannelSender<T> {
+    fn send(&self, value: T) -> Result<(), T>;
+}
+
+/// the receiving half of the common interface, where None means no msg is ever coming any more
+pub trait ChannelReceiver<T> {
+    fn recv(&self) -> Option<T>;
+
+    /// a receiver of the msgs of this one passed through `f`
+    fn map<U, F>(self, f: F) -> combinators::Map<Self, F, T>
+    where
+        Self: Sized,
+        F: Fn(T) -> U,
+    {
+        combinators::Map { receiver: self, f, msg_type: std::marker::PhantomData }
+    }
+
+    /// a receiver of only the msgs of this one that satisfy `predicate`, the rest being dropped
+    fn filter<P>(self, predicate: P) -> combinators::Filter<Self, P>
+    where
+        Self: Sized,
+        P: Fn(&T) -> bool,
+    {
+        combinators::Filter { receiver: self, predicate }
+    }
+}
+
 pub mod chennel_only_channel {
     use std::sync::Mutex;
+    use std::sync::MutexGuard;
+    use std::sync::PoisonError;
     use std::sync::Condvar;
     use std::collections::VecDeque;
+    use std::fmt;
+    use std::error::Error;
+
+    use super::{ChannelReceiver, ChannelSender};
 
     pub struct Channel<T> {
-        msg_queue: Mutex<VecDeque<T>>,
+        state: Mutex<ChannelState<T>>,
         recv_wakeup_flag: Condvar,
+        // only ever waited on by senders of a bounded channel, see Channel::with_capacity
+        send_wakeup_flag: Condvar,
+        capacity: Option<usize>,
+    }
+
+    struct ChannelState<T> {
+        msg_queue: VecDeque<T>,
+        // see Channel::close
+        closed: bool,
+        // the most msgs queued up at once since the last shrink_to_fit, being what the buffer needs
+        // room for under the load of late
+        high_watermark: usize,
+    }
+
+    /// the error of a recv on a channel that's closed and drained, see Channel::close
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Closed;
+
+    impl fmt::Display for Closed {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("receiving on a closed and drained channel")
+        }
     }
+
+    impl Error for Closed {}
     
     impl<T> Channel<T> {
         pub fn new() -> Self {
             Self {
-                msg_queue: Mutex::new(VecDeque::default()),
+                state: Mutex::new(ChannelState {
+                    msg_queue: VecDeque::default(),
+                    closed: false,
+                    high_watermark: 0,
+                }),
+                recv_wakeup_flag: Condvar::new(),
+                send_wakeup_flag: Condvar::new(),
+                capacity: None,
+            }
+        }
+
+        /// bounded flavor of the channel, where a send blocks while `capacity` msgs are queued up,
+        /// until a recv makes room for it, which is how a slow consumer pushes back on its producers.
+        /// nothing is allocated up front, the buffer growing with the msgs actually sent, s.t. a
+        /// channel with a generous capacity that's hardly ever used costs next to nothing
+        pub fn with_capacity(capacity: usize) -> Self {
+            assert!(capacity > 0, "capacity of a bounded channel must be non-zero");
+            Self {
+                state: Mutex::new(ChannelState {
+                    msg_queue: VecDeque::new(),
+                    closed: false,
+                    high_watermark: 0,
+                }),
                 recv_wakeup_flag: Condvar::new(),
+                send_wakeup_flag: Condvar::new(),
+                capacity: Some(capacity),
             }
         }
+
+        // a thread panicking with the lock held poisons the mutex, but every critical section here is
+        // a single push or pop that either happens or not, leaving the state consistent all the same,
+        // so the channel carries on rather than spreading the panic to every other thread using it
+        fn lock_state(&self) -> MutexGuard<'_, ChannelState<T>> {
+            self.state.lock().unwrap_or_else(PoisonError::into_inner)
+        }
     
-        pub fn send(&self, value: T) {
-            let mut q_guard = self.msg_queue.lock().unwrap();
-            q_guard.push_back(value);
+        /// hands the value back once the channel is closed, including while blocked on a full channel
+        pub fn send(&self, value: T) -> Result<(), T> {
+            let mut state_guard = self.lock_state();
+            loop {
+                if state_guard.closed {
+                    return Err(value);
+                }
+                if self.capacity.is_none_or(|capacity| state_guard.msg_queue.len() < capacity) {
+                    break;
+                }
+                state_guard = self.send_wakeup_flag.wait(state_guard).unwrap_or_else(PoisonError::into_inner);
+            }
+            state_guard.msg_queue.push_back(value);
+            state_guard.high_watermark = state_guard.high_watermark.max(state_guard.msg_queue.len());
             self.recv_wakeup_flag.notify_one();
+            Ok(())
         }
     
-        pub fn recv(&self) -> T {
-            let mut q_guard = self.msg_queue.lock().unwrap();
+        pub fn recv(&self) -> Result<T, Closed> {
+            let mut state_guard = self.lock_state();
             loop {
-                match q_guard.pop_front() {
+                match state_guard.msg_queue.pop_front() {
+                    None if state_guard.closed => {
+                        return Err(Closed);
+                    },
                     None => {
-                        q_guard = self.recv_wakeup_flag.wait(q_guard).unwrap();
+                        state_guard = self.recv_wakeup_flag.wait(state_guard).unwrap_or_else(PoisonError::into_inner);
                     },
                     Some(msg) => {
-                        return msg;
+                        if self.capacity.is_some() {
+                            self.send_wakeup_flag.notify_one();
+                        }
+                        return Ok(msg);
                     }
                 }
             }
         }
+
+        /// shuts the channel down, after which sends fail, and recvs fail as well once the msgs
+        /// already queued up are received, rather than blocking forever as there is no telling
+        /// otherwise whether any more msgs are coming. every consumer blocked in a recv is woken up,
+        /// as is every producer blocked on a full channel
+        pub fn close(&self) {
+            self.lock_state().closed = true;
+            self.recv_wakeup_flag.notify_all();
+            self.send_wakeup_flag.notify_all();
+        }
+
+        /// gives back the memory of the buffer beyond what the msgs have needed since the last call,
+        /// as a burst of msgs leaves the buffer grown for good otherwise. to be called now and then on
+        /// a long-lived channel, e.g. on a timer, s.t. memory is only held on to for a load that
+        /// keeps coming back
+        pub fn shrink_to_fit(&self) {
+            let mut state_guard = self.lock_state();
+            let high_watermark = state_guard.high_watermark;
+            state_guard.msg_queue.shrink_to(high_watermark);
+            state_guard.high_watermark = state_guard.msg_queue.len();
+        }
+
+        /// number of msgs the buffer has room for as allocated right now, see shrink_to_fit
+        pub fn buffer_capacity(&self) -> usize {
+            self.lock_state().msg_queue.capacity()
+        }
+    }
+
+    impl<T> Default for Channel<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T> Channel<T> {
+        /// splits the channel into a sending and a receiving handle that merely borrow it, for a
+        /// channel owned by the stack frame that spawns the producer and consumer with thread::scope,
+        /// where an Arc, and the heap allocation of the shared state that comes with it, would be
+        /// of no use. the sending handle is Copy, to be handed out to as many producers as needed
+        pub fn split(&self) -> (Sender<'_, T>, Receiver<'_, T>) {
+            (Sender { channel: self }, Receiver { channel: self })
+        }
+    }
+
+    /// see Channel::split
+    pub struct Sender<'a, T> {
+        channel: &'a Channel<T>,
+    }
+
+    impl<T> Sender<'_, T> {
+        pub fn send(&self, value: T) -> Result<(), T> {
+            self.channel.send(value)
+        }
+
+        pub fn close(&self) {
+            self.channel.close()
+        }
+    }
+
+    // derived impls would require T: Clone, which a shared reference has no need for
+    impl<T> Clone for Sender<'_, T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for Sender<'_, T> {}
+
+    /// see Channel::split
+    pub struct Receiver<'a, T> {
+        channel: &'a Channel<T>,
+    }
+
+    impl<T> Receiver<'_, T> {
+        pub fn recv(&self) -> Result<T, Closed> {
+            self.channel.recv()
+        }
+
+        pub fn close(&self) {
+            self.channel.close()
+        }
+    }
+
+    /// the one struct stands for both ends of the channel
+    impl<T> ChannelSender<T> for Channel<T> {
+        fn send(&self, value: T) -> Result<(), T> {
+            Channel::send(self, value)
+        }
+    }
+
+    impl<T> ChannelReceiver<T> for Channel<T> {
+        fn recv(&self) -> Option<T> {
+            Channel::recv(self).ok()
+        }
+    }
+
+    impl<T> ChannelSender<T> for Sender<'_, T> {
+        fn send(&self, value: T) -> Result<(), T> {
+            Sender::send(self, value)
+        }
+    }
+
+    impl<T> ChannelReceiver<T> for Receiver<'_, T> {
+        fn recv(&self) -> Option<T> {
+            Receiver::recv(self).ok()
+        }
     }
 }
 
-mod tx_rx_channel {
-    use std::sync::Arc;
-    use std::sync::Mutex;
-    use std::sync::Condvar;
-    use std::collections::VecDeque;
-    
+pub mod tx_rx_channel {
+    use super::sync::{self, Arc, Condvar, Mutex, MutexGuard};
+    #[cfg(not(loom))]
+    use super::sync::Weak;
+    use std::collections::{HashMap, VecDeque};
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll, Waker};
+    use std::time::{Duration, Instant};
+    use std::fmt;
+    use std::error::Error;
+
+    use super::{ChannelReceiver, ChannelSender};
+    use super::segmented::SegmentedQueue;
+    pub use super::error::{NoMoreReceiverErr, NoMoreSenderErr, TryRecvError};
+
     pub struct Sender<T> {
         shared_inner: Arc<SharedInner<T>>,
+        // tells the msgs of this sender from those of the others, for the order checks of a
+        // sequenced channel
+        id: u64,
     }
 
     impl<T> Sender<T> {
+        /// sends the msg, blocking on a full bounded channel, where the Fail overflow policy is left
+        /// to the sends that can tell a full channel apart, try_send and send_timeout, and waits as
+        /// for Block. the msg is only handed back for the receivers being gone
         pub fn send(&self, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+            self.send_via(value, |value| value, |value| value, |value| value)
+        }
+
+        /// send of a msg built by `make_value` only once it's settled that the msg goes into the
+        /// queue, s.t. a large payload isn't built just to be handed back for a closed channel, or
+        /// while the sender waits on a full one. the error carries no value when the msg wasn't built, and the one built when a
+        /// rendezvous fell through
+        pub fn send_with(&self, make_value: impl FnOnce() -> T) -> Result<(), NoMoreReceiverErr<Option<T>>> {
+            self.send_via(make_value, |make_value| make_value(), |_| None, Some)
+        }
+
+        /// send of a msg built on the heap, which is only moved out of the Box into the queue once
+        /// it's settled that it goes in there, and handed back still boxed otherwise
+        pub fn send_boxed(&self, value: Box<T>) -> Result<(), NoMoreReceiverErr<Box<T>>> {
+            self.send_via(value, |value| *value, |value| value, Box::new)
+        }
+
+        // the blocking send shared by the flavors above, where `source` is turned into the msg by
+        // `build` right before it's queued, and otherwise into what a failed send hands back by
+        // `unsent`, or by `undelivered` for a msg built but never taken in a rendezvous
+        fn send_via<S, E>(
+            &self,
+            source: S,
+            build: impl FnOnce(S) -> T,
+            unsent: impl FnOnce(S) -> E,
+            undelivered: impl FnOnce(T) -> E,
+        ) -> Result<(), NoMoreReceiverErr<E>> {
             // acquire lock to the mutable common data to access the msg queue to push a msg
-            // dropping the lock guard to release the lock after the expression
-            self.shared_inner.inner_mut_data.lock().unwrap().msg_queue.push_back(value);
-            self.shared_inner.recv_wakeup_flag.notify_one();
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            loop {
+                // there is no point in queueing up a msg that nobody would ever receive, so the value
+                // is handed back to the caller instead, which is also the case once the channel is closed
+                if shared_mut_data_guard.is_send_closed() {
+                    return Err(NoMoreReceiverErr(unsent(source)));
+                }
+                if !shared_mut_data_guard.is_full() {
+                    break;
+                }
+                match shared_mut_data_guard.on_full() {
+                    // for a bounded channel, the send becomes a blocking call while the queue is at capacity,
+                    // waiting on the cond var that the receiving end notifies each time it takes a msg out
+                    // (or when it's dropped, in which case the check above would bail out). the same goes
+                    // for the Fail policy, which a send failing only for want of receivers can't report
+                    OnFull::Wait | OnFull::Reject => {
+                        shared_mut_data_guard = self.shared_inner.wait_for_slot(shared_mut_data_guard, None, SharedInnerMut::slot_pending);
+                    },
+                    OnFull::Proceed => break,
+                    OnFull::Discard => return Ok(()),
+                }
+            }
+            let value = build(source);
+            if shared_mut_data_guard.is_rendezvous() {
+                let ticket = self.enqueue(shared_mut_data_guard, value);
+                return self.await_handoff(ticket, None, false).map_err(|handoff_err| match handoff_err {
+                    // a handoff with no deadline only ever fails for the receivers being gone, the full
+                    // queue of a rendezvous channel being waited on whatever the overflow policy
+                    SendTimeoutError::Disconnected(value) | SendTimeoutError::Timeout(value) | SendTimeoutError::Full(value) => {
+                        NoMoreReceiverErr(undelivered(value))
+                    },
+                });
+            }
+            self.enqueue(shared_mut_data_guard, value);
+            Ok(())
+        }
+
+        /// non-blocking counterpart of send that hands the value back right away, instead of blocking,
+        /// when a bounded channel is at capacity, leaving it to the caller to decide how to back off
+        ///
+        /// on a rendezvous channel, the msg is only sent to a receiver already waiting for one, be it
+        /// a thread blocked in a recv call or a task parked on a recv_async future, and try_send returns
+        /// once that receiver has taken it. should the receiver go away in the meantime, e.g. for its
+        /// future being dropped, the msg is handed back as if the channel had been full. a thread
+        /// driving the task of that receiver is not to call try_send, as it would wait for itself
+        pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            if shared_mut_data_guard.is_send_closed() {
+                return Err(TrySendError::Disconnected(value));
+            }
+            if shared_mut_data_guard.is_full() {
+                return Err(TrySendError::Full(value));
+            }
+            // without blocking, a rendezvous can only happen with a receiver already waiting for it
+            if shared_mut_data_guard.is_rendezvous() {
+                if !shared_mut_data_guard.is_receiver_waiting() {
+                    return Err(TrySendError::Full(value));
+                }
+                let ticket = self.enqueue(shared_mut_data_guard, value);
+                return self.await_handoff(ticket, None, true).map_err(|handoff_err| match handoff_err {
+                    SendTimeoutError::Disconnected(value) => TrySendError::Disconnected(value),
+                    SendTimeoutError::Timeout(value) | SendTimeoutError::Full(value) => TrySendError::Full(value),
+                });
+            }
+            self.enqueue(shared_mut_data_guard, value);
+            Ok(())
+        }
+
+        /// blocking send for a bounded channel that gives up waiting for capacity once the given
+        /// timeout has elapsed, handing the unsent value back in the error
+        pub fn send_timeout(&self, value: T, timeout: Duration) -> Result<(), SendTimeoutError<T>> {
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            // a timeout too long for an Instant to reach, e.g. Duration::MAX, is no timeout at all
+            let deadline = shared_mut_data_guard.clock.now().checked_add(timeout);
+            loop {
+                if shared_mut_data_guard.is_send_closed() {
+                    return Err(SendTimeoutError::Disconnected(value));
+                }
+                if !shared_mut_data_guard.is_full() {
+                    break;
+                }
+                match shared_mut_data_guard.on_full() {
+                    OnFull::Wait => {},
+                    OnFull::Proceed => break,
+                    OnFull::Discard => return Ok(()),
+                    // a channel failing sends on overflow doesn't wait for capacity at all, and says so
+                    // rather than passing it off as a timeout
+                    OnFull::Reject => return Err(SendTimeoutError::Full(value)),
+                }
+                if shared_mut_data_guard.is_past(deadline) {
+                    return Err(SendTimeoutError::Timeout(value));
+                }
+                shared_mut_data_guard = self.shared_inner.wait_for_slot(shared_mut_data_guard, deadline, SharedInnerMut::slot_pending);
+            }
+            if shared_mut_data_guard.is_rendezvous() {
+                let ticket = self.enqueue(shared_mut_data_guard, value);
+                return self.await_handoff(ticket, deadline, false);
+            }
+            self.enqueue(shared_mut_data_guard, value);
+            Ok(())
+        }
+
+        /// shuts the channel down without dropping this sender: every send from then on fails right
+        /// away, while the receivers still get the msgs already queued up before being told of the
+        /// disconnection. closing is for the whole channel, whichever sender or receiver does it
+        pub fn close(&self) {
+            self.shared_inner.close();
+        }
+
+        /// whether any send from now on is bound to fail, the receivers being all gone or the channel
+        /// closed, which is cheaper than finding out by a send and its error
+        pub fn is_closed(&self) -> bool {
+            self.shared_inner.inner_mut_data.lock().unwrap().is_send_closed()
+        }
+
+        /// whether both handles are to the same channel, e.g. to tell apart the senders kept in a
+        /// collection
+        pub fn same_channel(&self, other: &Sender<T>) -> bool {
+            Arc::ptr_eq(&self.shared_inner, &other.shared_inner)
+        }
+
+        /// a handle to the channel that doesn't count as a sender, s.t. holding it doesn't keep the
+        /// receivers from finding out that all the (strong) senders are gone
+        #[cfg(not(loom))]
+        pub fn downgrade(&self) -> WeakSender<T> {
+            WeakSender {
+                shared_inner: Arc::downgrade(&self.shared_inner),
+            }
+        }
+
+        /// the label given to the channel by ChannelBuilder::name, if any
+        pub fn name(&self) -> Option<&str> {
+            self.shared_inner.name.as_deref()
+        }
+
+        /// number of senders of the channel alive right now, this one included, where a count higher
+        /// than expected gives away a sender leaked somewhere. weak senders don't count
+        pub fn sender_count(&self) -> usize {
+            self.shared_inner.inner_mut_data.lock().unwrap().sender_cnt
+        }
+
+        /// number of receivers of the channel alive right now, none meaning any send is bound to fail
+        pub fn receiver_count(&self) -> usize {
+            self.shared_inner.inner_mut_data.lock().unwrap().receiver_cnt
+        }
+
+        /// snapshot of the counters kept by the channel since its creation, the same whichever sender
+        /// or receiver it's taken from
+        pub fn stats(&self) -> ChannelStats {
+            self.shared_inner.inner_mut_data.lock().unwrap().stats()
+        }
+
+        /// number of msgs currently queued up in the channel
+        pub fn len(&self) -> usize {
+            self.shared_inner.inner_mut_data.lock().unwrap().msg_queue.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// max number of msgs the channel can queue up, None for an unbounded channel
+        pub fn capacity(&self) -> Option<usize> {
+            self.shared_inner.inner_mut_data.lock().unwrap().capacity
+        }
+
+        /// changes the capacity of a bounded channel on the fly, to adapt the backpressure to the
+        /// load without recreating the channel. growing lets blocked senders through right away,
+        /// whereas shrinking below the msgs already queued up leaves them be, with sends blocking
+        /// until the receivers drain the queue below the new capacity. a rendezvous channel, and an
+        /// unbounded one, are what they are for good, and no channel can be turned into one
+        pub fn set_capacity(&self, capacity: usize) -> Result<(), SetCapacityError> {
+            self.shared_inner.set_capacity(capacity)
+        }
+
+        /// blocks until the queue of a bounded channel has room for at least `n` more msgs, without
+        /// sending anything, s.t. a producer can hold off building msgs that are expensive to make
+        /// until the receivers catch up. nothing is reserved though, and a send right after may
+        /// still block once other senders got to the room first. an unbounded channel always has room
+        ///
+        /// fails with Unreachable right away for `n` beyond the capacity of the channel, or once the
+        /// capacity is shrunk below `n` by set_capacity during the wait
+        pub fn wait_capacity(&self, n: usize) -> Result<(), WaitCapacityError> {
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            loop {
+                if shared_mut_data_guard.is_send_closed() {
+                    return Err(WaitCapacityError::Disconnected);
+                }
+                if shared_mut_data_guard.capacity.is_some_and(|capacity| n > capacity.max(1)) {
+                    return Err(WaitCapacityError::Unreachable);
+                }
+                if shared_mut_data_guard.free_slots() >= n {
+                    return Ok(());
+                }
+                shared_mut_data_guard.capacity_waiter_cnt += 1;
+                shared_mut_data_guard.stats.blocking_waits += 1;
+                shared_mut_data_guard = self.shared_inner.send_wakeup_flag.wait(shared_mut_data_guard).unwrap();
+                shared_mut_data_guard.capacity_waiter_cnt -= 1;
+            }
+        }
+
+        // the second half of a send on a rendezvous channel, where the msg is already in the queue and
+        // the sender waits for a receiver to take it out. when that doesn't happen, because all the
+        // receivers are gone or because the deadline is reached, the msg is taken back out of the queue
+        // to be handed back in the error. the handoff of a try_send, `to_waiting_receiver`, only waits
+        // for as long as there is a receiver waiting to take it, and fails with Full otherwise
+        fn await_handoff(&self, ticket: u64, deadline: Option<Instant>, to_waiting_receiver: bool) -> Result<(), SendTimeoutError<T>> {
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            loop {
+                if shared_mut_data_guard.taken_cnt >= ticket {
+                    return Ok(());
+                }
+                // a rendezvous channel lets one msg into the queue at a time, so the msg not taken yet
+                // is still the only one in there
+                if shared_mut_data_guard.is_send_closed() {
+                    let value = shared_mut_data_guard.retract_unhanded_msg();
+                    self.shared_inner.send_wakeup_flag.notify_all();
+                    return Err(SendTimeoutError::Disconnected(value));
+                }
+                if shared_mut_data_guard.is_past(deadline) {
+                    let value = shared_mut_data_guard.retract_unhanded_msg();
+                    self.shared_inner.send_wakeup_flag.notify_all();
+                    return Err(SendTimeoutError::Timeout(value));
+                }
+                if to_waiting_receiver && !shared_mut_data_guard.is_receiver_waiting() {
+                    let value = shared_mut_data_guard.retract_unhanded_msg();
+                    self.shared_inner.send_wakeup_flag.notify_all();
+                    return Err(SendTimeoutError::Full(value));
+                }
+                // what the sender waits for is the pickup of its own msg, not for the queue to have
+                // room, which it may well have again by then for the msg of another sender
+                let handoff_pending = |shared_mut_data: &SharedInnerMut<T>| {
+                    shared_mut_data.taken_cnt < ticket
+                        && !shared_mut_data.is_send_closed()
+                        && (!to_waiting_receiver || shared_mut_data.is_receiver_waiting())
+                };
+                shared_mut_data_guard = self.shared_inner.wait_for_slot(shared_mut_data_guard, deadline, handoff_pending);
+            }
+        }
+
+        // common tail of all the flavors of send, once it's settled that the msg goes into the queue,
+        // which is to push it and then let one waiting receiver know, be it a thread blocked in a recv
+        // call or an async task parked on a recv_async future. the returned ticket is the number of
+        // msgs taken out of the queue by the time this msg is
+        fn enqueue(&self, mut shared_mut_data_guard: MutexGuard<'_, SharedInnerMut<T>>, value: T) -> u64 {
+            shared_mut_data_guard.push_msg(value, self.id);
+            let ticket = shared_mut_data_guard.sent_cnt;
+            self.shared_inner.msgs_enqueued(shared_mut_data_guard, 1);
+            ticket
+        }
+
+        /// sends a whole batch of msgs, pushing as many of them into the queue as it has room for under
+        /// a single acquisition of the lock, followed by a single round of notification, rather than
+        /// paying for both on every msg. on a bounded channel, the send blocks in between whenever the
+        /// queue is full, or applies the overflow policy of the channel, the Fail one waiting as for
+        /// Block, see send. when the receivers are gone midway, the msgs not sent yet are handed back
+        pub fn send_all<I: IntoIterator<Item = T>>(&self, values: I) -> Result<(), NoMoreReceiverErr<Vec<T>>> {
+            self.send_many(values, SharedInner::msgs_enqueued)
+        }
+
+        /// send_all for a burst of msgs already collected in a Vec, which go into the queue under a
+        /// single acquisition of the lock, given the room for them, with a single blocked receiver
+        /// woken up for the whole batch rather than one per msg. that receiver takes as many msgs as
+        /// it likes, e.g. all of them with recv_many, and passes the wakeup on to the next receiver
+        /// waiting as it leaves msgs behind, s.t. a burst wakes up no more receivers than it takes to
+        /// drain it. the msgs not sent on failure are handed back, as for send_all
+        pub fn send_batch(&self, values: Vec<T>) -> Result<(), NoMoreReceiverErr<Vec<T>>> {
+            self.send_many(values, SharedInner::batch_enqueued)
+        }
+
+        // the body of send_all and send_batch, which only differ in how many receivers they wake up
+        // for the msgs pushed in one go, by `msgs_enqueued`
+        fn send_many<I: IntoIterator<Item = T>>(
+            &self,
+            values: I,
+            msgs_enqueued: fn(&SharedInner<T>, MutexGuard<'_, SharedInnerMut<T>>, usize),
+        ) -> Result<(), NoMoreReceiverErr<Vec<T>>> {
+            let mut values = values.into_iter();
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            // every msg of a rendezvous needs a handoff of its own anyway
+            if shared_mut_data_guard.is_rendezvous() {
+                drop(shared_mut_data_guard);
+                while let Some(value) = values.next() {
+                    if let Err(NoMoreReceiverErr(value)) = self.send(value) {
+                        return Err(NoMoreReceiverErr(std::iter::once(value).chain(values).collect()));
+                    }
+                }
+                return Ok(());
+            }
+
+            // number of msgs pushed that the receivers haven't been notified about yet
+            let mut pending_cnt = 0;
+            'values: while let Some(value) = values.next() {
+                loop {
+                    if shared_mut_data_guard.is_send_closed() {
+                        return Err(NoMoreReceiverErr(std::iter::once(value).chain(values).collect()));
+                    }
+                    if !shared_mut_data_guard.is_full() {
+                        break;
+                    }
+                    match shared_mut_data_guard.on_full() {
+                        OnFull::Wait | OnFull::Reject => {},
+                        OnFull::Proceed => break,
+                        OnFull::Discard => continue 'values,
+                    }
+                    // the receivers can only make room in the queue once they know about the msgs
+                    // already pushed, so they are notified before this send blocks
+                    if pending_cnt > 0 {
+                        msgs_enqueued(&self.shared_inner, shared_mut_data_guard, pending_cnt);
+                        pending_cnt = 0;
+                        shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+                        continue;
+                    }
+                    shared_mut_data_guard = self.shared_inner.wait_for_slot(shared_mut_data_guard, None, SharedInnerMut::slot_pending);
+                }
+                shared_mut_data_guard.push_msg(value, self.id);
+                pending_cnt += 1;
+            }
+            if pending_cnt > 0 {
+                msgs_enqueued(&self.shared_inner, shared_mut_data_guard, pending_cnt);
+            }
             Ok(())
         }
     }
 
-    pub struct NoMoreReceiverErr<T>(pub T);
+    /// what a send on a bounded channel does when the queue is at capacity, chosen once for the
+    /// whole channel at construction. the non-blocking try_send is left out of it, as it has its
+    /// own way of telling a full channel apart, and the blocking sends, whose error only tells of
+    /// the receivers being gone, wait for room under the Fail policy
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum OverflowPolicy {
+        /// wait for a receiver to make room, the classic backpressure of a bounded channel
+        #[default]
+        Block,
+        /// discard the msg being sent, the send still reporting success
+        DropNewest,
+        /// evict the oldest msg in the queue to make room for the one being sent, e.g. for telemetry
+        /// where stale readings aren't worth holding up the producer for
+        DropOldest,
+        /// hand the msg right back in a SendTimeoutError::Full, without waiting out the timeout of
+        /// send_timeout
+        Fail,
+    }
+
+    // what a send running into a full queue is to do about it, as decided by the overflow policy
+    enum OnFull {
+        Wait,
+        // room has been made in the queue for the msg
+        Proceed,
+        Discard,
+        Reject,
+    }
+
+    /// counters to diagnose how a channel is used, e.g. whether a bounded channel is too small for
+    /// its load, where senders would block a lot with the queue often at its peak depth
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct ChannelStats {
+        /// msgs that went into the queue
+        pub sent: u64,
+        /// msgs taken out of the queue by the receivers
+        pub received: u64,
+        /// the most msgs ever queued up at once
+        pub peak_depth: usize,
+        /// times a sender or receiver blocked waiting on the other end
+        pub blocking_waits: u64,
+        /// msgs skipped by the receivers for having outlived the ttl of the channel, which don't
+        /// count as received
+        pub expired: u64,
+        /// msgs lost to the overflow policy of a full channel, be it the msg sent or the oldest one
+        pub dropped: u64,
+    }
+
+    /// the two reasons a non-blocking send can fail, both giving the unsent value back to the caller
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum TrySendError<T> {
+        Full(T),
+        Disconnected(T),
+    }
+
+    /// the reasons a send bounded by a timeout can fail, all giving the unsent value back, where
+    /// Full only ever comes up on a bounded channel with the Fail overflow policy, which doesn't wait
+    /// out the timeout
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum SendTimeoutError<T> {
+        Timeout(T),
+        Full(T),
+        Disconnected(T),
+    }
+
+    /// the reasons Sender::set_capacity can turn down a new capacity
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum SetCapacityError {
+        /// the channel is unbounded or a rendezvous channel, which it stays for good
+        FixedCapacity,
+        /// a capacity of 0, which would make a rendezvous channel out of a bounded one
+        ZeroCapacity,
+    }
+
+    /// the two reasons Sender::wait_capacity can give up waiting
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum WaitCapacityError {
+        /// the room waited for is more than the capacity of the channel, for now or for good
+        Unreachable,
+        Disconnected,
+    }
 
     /// Clone and Drop, together, are all the interfaces on Sender that affect the count of senders
     /// in the mpsc setup, whose implementation is all it takes to keep track of the right count
     impl<T> Clone for Sender<T> {
         fn clone(&self) -> Self {
-            self.shared_inner.inner_mut_data.lock().unwrap().sender_cnt += 1;
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            shared_mut_data_guard.sender_cnt += 1;
+            let id = shared_mut_data_guard.new_sender_id();
+            drop(shared_mut_data_guard);
             // return a new Sender wrapping the shared inner with updated sender
             // count, as the return of the clone of an existing Sender
             Sender {
-                shared_inner: Arc::clone(&self.shared_inner)
+                shared_inner: Arc::clone(&self.shared_inner),
+                id,
             }
         }
     }
@@ -79,157 +763,2863 @@ mod tx_rx_channel {
         fn drop(&mut self) {
             let mut inner_mut_data_lock = self.shared_inner.inner_mut_data.lock().unwrap();
             inner_mut_data_lock.sender_cnt -= 1;
-            dbg!(inner_mut_data_lock.sender_cnt);
             if inner_mut_data_lock.sender_cnt == 0 {
+                let recv_wakers = std::mem::take(&mut inner_mut_data_lock.recv_wakers);
                 drop(inner_mut_data_lock);
-                self.shared_inner.recv_wakeup_flag.notify_one();
+                #[cfg(feature = "tracing")]
+                tracing::debug!(channel = self.shared_inner.name.as_deref(), "all senders gone");
+                // unlike a new msg, which only one of the receivers gets to take, the disconnection
+                // concerns every receiver blocked in a recv call
+                self.shared_inner.recv_wakeup_flag.notify_all();
+                recv_wakers.into_iter().for_each(Waker::wake);
             }
         }
     }
 
-    pub struct Receiver<T> {
-        shared_inner: Arc<SharedInner<T>>,
+    impl<T> ChannelSender<T> for Sender<T> {
+        fn send(&self, value: T) -> Result<(), T> {
+            Sender::send(self, value).map_err(NoMoreReceiverErr::into_inner)
+        }
     }
 
-    #[derive(Debug)]
-    pub struct NoMoreSenderErr;
+    /// see Sender::downgrade
+    #[cfg(not(loom))]
+    pub struct WeakSender<T> {
+        shared_inner: Weak<SharedInner<T>>,
+    }
 
-    impl<T> Receiver<T> {
-        
-        /// bogus implementation of recv that would hang forever, in the case that there is no msg to receive from the 
-        /// channel and all the senders have been dropped where there is no point to block waiting for msg any more
-        pub fn tx_unaware_recv(&self) -> T {
-            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
-            loop {
-                if let Some(msg) = shared_mut_data_guard.msg_queue.pop_front() {
-                    return msg;
-                }
-                // core implementation to enable the receive to become a blocking call in this case
-                // when there is no current msg to receive from the channel is to meke use of the cond var that
-                // is meant to notify the presence of a new sent msg s.t. the cond var is waited atomically with 
-                // the release of lock held on this receiving end right now, and reacquiring the lock on the
-                // cond var's notification to proceed into the next round of the loop, where the sent msg would be returned
-                shared_mut_data_guard = self.shared_inner.recv_wakeup_flag.wait(shared_mut_data_guard).unwrap();
+    #[cfg(not(loom))]
+    impl<T> WeakSender<T> {
+        /// turns back into a full-fledged sender, as long as there is still one around, as once the
+        /// count of senders drops to zero the receivers are told of the disconnection for good
+        pub fn upgrade(&self) -> Option<Sender<T>> {
+            let shared_inner = self.shared_inner.upgrade()?;
+            let mut shared_mut_data_guard = shared_inner.inner_mut_data.lock().unwrap();
+            if shared_mut_data_guard.sender_cnt == 0 || shared_mut_data_guard.closed {
+                return None;
             }
+            shared_mut_data_guard.sender_cnt += 1;
+            let id = shared_mut_data_guard.new_sender_id();
+            drop(shared_mut_data_guard);
+            Some(Sender { shared_inner, id })
         }
+    }
 
-        pub fn recv(&self) -> Result<T, NoMoreSenderErr> {
-            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
-            loop {
-                if let Some(msg) = shared_mut_data_guard.msg_queue.pop_front() {
-                    return Ok(msg);
-                } else {
-                    // here in the `else` branch due to the fact that the exucution of the call finds out that
-                    // there is no data in the channel to receive, further divided in two cases
-                    if shared_mut_data_guard.sender_cnt == 0 {
-                        // in case that there is no sender in the mpsc setup, plus no msg to receive from the channel
-                        return Err(NoMoreSenderErr)
-                    } else {
-                        // otherwise the receive becomes a blocking call that proceeds when further msg is sent by any sender
-                        shared_mut_data_guard = self.shared_inner.recv_wakeup_flag.wait(shared_mut_data_guard).unwrap();
-                    }
-                }
+    #[cfg(not(loom))]
+    impl<T> Clone for WeakSender<T> {
+        fn clone(&self) -> Self {
+            WeakSender {
+                shared_inner: Weak::clone(&self.shared_inner),
             }
         }
     }
-    
-    // modelling the ONE common entity shared (by means of Arc pointer) among the sender(s) and the one receiver
-    // in a mpsc setting
-    struct SharedInner<T> {
-        inner_mut_data: Mutex<SharedInnerMut<T>>,
-        recv_wakeup_flag: Condvar,
-    }
 
-    // modelling the data parts, within the the common entity as above, that both sender(s) and receiver parties
-    // would mutate, synchronized by Mutex in this implementation
-    struct SharedInnerMut<T> {
-        msg_queue: VecDeque<T>,
-        // these fields are to keep a correct account of number of sender and the presence of receiver
-        // in the mpsc setup. the reference count to the common shared entity of the channel cannot naively
-        // give the accurate account, for example, when querying whether there is any senders left, a reference
-        // count of 1 wouldn't tell whether that's 1 sender or receiver left alive
-        sender_cnt: usize,
-        receiver_live: bool,
+    pub struct Receiver<T> {
+        shared_inner: Arc<SharedInner<T>>,
     }
 
-    impl<T> SharedInnerMut<T> {
-        // provide utility to intialize such structured, ready to be called by public-facing API for creating new channel
-        fn new() -> Self {
-            Self {
-                msg_queue: VecDeque::new(),
-                sender_cnt: 1,
-                receiver_live: true,
+    /// mirroring Sender, cloning a Receiver turns the channel into a mpmc setup where the receivers
+    /// compete for the msgs, each msg being delivered to exactly one of them, e.g. a work queue shared
+    /// by a pool of worker threads
+    impl<T> Clone for Receiver<T> {
+        fn clone(&self) -> Self {
+            self.shared_inner.inner_mut_data.lock().unwrap().receiver_cnt += 1;
+            Receiver {
+                shared_inner: Arc::clone(&self.shared_inner)
             }
         }
     }
-    
-    pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
-        
-        let new_shared_inner = Arc::new(SharedInner {
-            inner_mut_data: Mutex::new(SharedInnerMut::new()),
-            recv_wakeup_flag: Condvar::new(),
-        });
-        
-        ( 
-            Sender { shared_inner: Arc::clone(&new_shared_inner) },
-            Receiver { shared_inner: Arc::clone(&new_shared_inner) },
-        )
+
+    impl<T> Drop for Receiver<T> {
+        fn drop(&mut self) {
+            let mut inner_mut_data_lock = self.shared_inner.inner_mut_data.lock().unwrap();
+            inner_mut_data_lock.receiver_cnt -= 1;
+            if inner_mut_data_lock.receiver_cnt == 0 {
+                let send_wakers = std::mem::take(&mut inner_mut_data_lock.send_wakers);
+                drop(inner_mut_data_lock);
+                #[cfg(feature = "tracing")]
+                tracing::debug!(channel = self.shared_inner.name.as_deref(), "all receivers gone");
+                // every sender blocked on a full bounded channel needs to find out that it should give up
+                self.shared_inner.send_wakeup_flag.notify_all();
+                send_wakers.into_iter().for_each(Waker::wake);
+            }
+        }
+    }
+
+    impl<T: Clone + Send + 'static> Receiver<T> {
+        /// fans the msgs of this receiver out to `n` new receivers, each of which gets a clone of every
+        /// msg, for one stream of msgs to feed several consumers independent of one another, as
+        /// opposed to the clones of a receiver competing for the msgs
+        ///
+        /// the msgs are forwarded by a thread of its own, which goes on until the senders of this
+        /// channel are gone, or all the new receivers are. the new channels have the capacity of this
+        /// one, s.t. a slow consumer holds up the forwarding for all the others, as it would hold up
+        /// the senders without the tee
+        ///
+        /// a tee to no receiver at all spawns no thread, and drops this receiver right away, as if
+        /// the msgs had nowhere to go, which they haven't
+        pub fn tee(self, n: usize) -> Vec<Receiver<T>> {
+            if n == 0 {
+                return Vec::new();
+            }
+            let capacity = self.capacity();
+            let (fanout_txs, fanout_rxs): (Vec<Sender<T>>, Vec<Receiver<T>>) = (0..n)
+                .map(|_| match capacity {
+                    Some(capacity) => sync_channel(capacity),
+                    None => channel(),
+                })
+                .unzip();
+            sync::thread::spawn(move || {
+                let mut fanout_txs = fanout_txs;
+                while let Ok(msg) = self.recv() {
+                    // a receiver dropped on the way has its sender dropped in turn
+                    fanout_txs.retain(|fanout_tx| fanout_tx.send(msg.clone()).is_ok());
+                    if fanout_txs.is_empty() {
+                        break;
+                    }
+                }
+            });
+            fanout_rxs
+        }
+    }
+
+    impl<T: Send + 'static> Receiver<T> {
+        /// a receiver of the msgs of both receivers, in whichever order they come in, with each
+        /// receiver forwarded by a thread of its own. the merged receiver is disconnected only once
+        /// both are
+        pub fn merge(first: Receiver<T>, second: Receiver<T>) -> Receiver<T> {
+            let (merged_tx, merged_rx) = channel();
+            for receiver in [first, second] {
+                let merged_tx = merged_tx.clone();
+                sync::thread::spawn(move || {
+                    while let Ok(msg) = receiver.recv() {
+                        if merged_tx.send(msg).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            merged_rx
+        }
+
+        /// with the `tokio` feature, a Stream of the msgs of this receiver for async code running on
+        /// tokio, ending once the senders of this channel are gone and the queue is drained
+        ///
+        /// the msgs are forwarded into a tokio::sync::mpsc channel by a thread of its own, rather than
+        /// a task of spawn_blocking, s.t. no runtime needs to be around at the call. the tokio channel
+        /// has the capacity of this one, or room for a single msg if this one is unbounded, the rest
+        /// waiting in the queue of this channel. dropping the stream stops the forwarding at the next
+        /// msg, and drops this receiver with it
+        #[cfg(feature = "tokio")]
+        pub fn into_tokio_stream(self) -> tokio_stream::wrappers::ReceiverStream<T> {
+            // a tokio channel can't be a rendezvous one
+            let capacity = self.capacity().map_or(1, |capacity| capacity.max(1));
+            let (tokio_tx, tokio_rx) = tokio::sync::mpsc::channel(capacity);
+            sync::thread::spawn(move || {
+                while let Ok(msg) = self.recv() {
+                    // blocking_send only panics within an async context, which this thread is not
+                    if tokio_tx.blocking_send(msg).is_err() {
+                        break;
+                    }
+                }
+            });
+            tokio_stream::wrappers::ReceiverStream::new(tokio_rx)
+        }
+    }
+
+    impl<T> ChannelReceiver<T> for Receiver<T> {
+        fn recv(&self) -> Option<T> {
+            Receiver::recv(self).ok()
+        }
+    }
+
+    /// see Receiver::recv_delivery
+    pub struct Delivery<'a, T> {
+        // the msg as it was queued up, kept whole to be put back as it was, None once acked
+        queued: Option<QueuedMsg<T>>,
+        receiver: &'a Receiver<T>,
+    }
+
+    impl<T> Delivery<'_, T> {
+        /// settles the delivery, handing over the msg for good, and its slot in the queue to the
+        /// senders
+        pub fn ack(mut self) -> T {
+            let queued = self.queued.take().unwrap();
+            let mut shared_mut_data_guard = self.receiver.shared_inner.inner_mut_data.lock().unwrap();
+            shared_mut_data_guard.unacked_cnt -= 1;
+            self.receiver.slots_freed(shared_mut_data_guard, 1);
+            queued.msg
+        }
+    }
+
+    impl<T> std::ops::Deref for Delivery<'_, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.queued.as_ref().unwrap().msg
+        }
+    }
+
+    impl<T> Drop for Delivery<'_, T> {
+        fn drop(&mut self) {
+            let Some(queued) = self.queued.take() else {
+                return;
+            };
+            // into the slot the msg has kept since the recv, s.t. the queue stays within its capacity
+            let shared_inner = &self.receiver.shared_inner;
+            let mut shared_mut_data_guard = shared_inner.inner_mut_data.lock().unwrap();
+            shared_mut_data_guard.unacked_cnt -= 1;
+            shared_mut_data_guard.requeue_msg(queued);
+            // the msg isn't sent anew, hence no word of it to the observer, unlike in msgs_enqueued
+            let recv_waker = shared_mut_data_guard.recv_wakers.pop_front();
+            drop(shared_mut_data_guard);
+            shared_inner.recv_wakeup_flag.notify_one();
+            recv_waker.into_iter().for_each(Waker::wake);
+        }
+    }
+
+    /// backing impl for recv_async
+    pub struct RecvFuture<'a, T> {
+        receiver: &'a Receiver<T>,
+        // the waker last left in the channel by this future, kept around to clean up after itself if
+        // the future is dropped before it resolves
+        registered_waker: Option<Waker>,
+    }
+
+    impl<'a, T> Future for RecvFuture<'a, T> {
+        type Output = Result<T, NoMoreSenderErr>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.get_mut();
+            let poll = this.receiver.poll_recv(cx, Some(this.registered_waker.is_some()));
+            this.registered_waker = poll.is_pending().then(|| cx.waker().clone());
+            poll
+        }
+    }
+
+    impl<'a, T> Drop for RecvFuture<'a, T> {
+        fn drop(&mut self) {
+            let Some(registered_waker) = self.registered_waker.take() else {
+                return;
+            };
+            let mut shared_mut_data_guard = self.receiver.shared_inner.inner_mut_data.lock().unwrap();
+            shared_mut_data_guard.parked_task_cnt -= 1;
+            // a try_send on a rendezvous channel may be waiting for this very task to take its msg
+            if shared_mut_data_guard.is_rendezvous() {
+                self.receiver.shared_inner.send_wakeup_flag.notify_all();
+            }
+            let registered_cnt = shared_mut_data_guard.recv_wakers.len();
+            shared_mut_data_guard.recv_wakers.retain(|registered| !registered.will_wake(&registered_waker));
+            // not finding its waker any more means this future was woken up for a msg it is now never
+            // going to take, so the wakeup is passed on to the next task in line lest the msg is stranded
+            if shared_mut_data_guard.recv_wakers.len() == registered_cnt && !shared_mut_data_guard.msg_queue.is_empty() {
+                if let Some(next_waker) = shared_mut_data_guard.recv_wakers.pop_front() {
+                    drop(shared_mut_data_guard);
+                    next_waker.wake();
+                }
+            }
+        }
+    }
+
+    /// backing impl for try_iter, yielding msgs by means of non-blocking try_recv calls
+    pub struct ReceiverTryIter<'a, T> {
+        receiver: &'a Receiver<T>,
+    }
+
+    impl<'a, T> Iterator for ReceiverTryIter<'a, T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.receiver.try_recv().ok()
+        }
+    }
+
+    /// backing impl for consuming an owned Receiver in a `for` loop, where each step is a blocking
+    /// recv and the iteration ends once all the senders are gone and the queue is drained
+    pub struct ReceiverIntoIter<T> {
+        receiver: Receiver<T>,
+    }
+
+    impl<T> Iterator for ReceiverIntoIter<T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.receiver.recv().ok()
+        }
+    }
+
+    impl<T> IntoIterator for Receiver<T> {
+        type Item = T;
+
+        type IntoIter = ReceiverIntoIter<T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            ReceiverIntoIter {
+                receiver: self,
+            }
+        }
+    }
+
+    /// the two reasons a receive bounded by a timeout can come back empty-handed
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum RecvTimeoutError {
+        Timeout,
+        Disconnected,
+    }
+
+    impl<T> TrySendError<T> {
+        pub fn into_inner(self) -> T {
+            match self {
+                TrySendError::Full(value) | TrySendError::Disconnected(value) => value,
+            }
+        }
+    }
+
+    impl<T> SendTimeoutError<T> {
+        pub fn into_inner(self) -> T {
+            match self {
+                SendTimeoutError::Timeout(value) | SendTimeoutError::Full(value) | SendTimeoutError::Disconnected(value) => value,
+            }
+        }
+    }
+
+    // the error types implement std::error::Error, for them to go through `?` into whatever error
+    // type the caller has, e.g. a Box<dyn Error>. the msg of a failed send is left out of Display,
+    // as it's there to be taken back by into_inner rather than to be shown
+
+    impl<T> fmt::Display for TrySendError<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                TrySendError::Full(_) => f.write_str("sending on a full channel"),
+                TrySendError::Disconnected(_) => f.write_str("sending on a channel with no receiver left"),
+            }
+        }
+    }
+
+    impl<T: fmt::Debug> Error for TrySendError<T> {}
+
+    impl<T> fmt::Display for SendTimeoutError<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                SendTimeoutError::Timeout(_) => f.write_str("timed out sending on a full channel"),
+                SendTimeoutError::Full(_) => f.write_str("sending on a full channel that fails on overflow"),
+                SendTimeoutError::Disconnected(_) => f.write_str("sending on a channel with no receiver left"),
+            }
+        }
+    }
+
+    impl<T: fmt::Debug> Error for SendTimeoutError<T> {}
+
+    impl fmt::Display for SetCapacityError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                SetCapacityError::FixedCapacity => f.write_str("changing the capacity of an unbounded or rendezvous channel"),
+                SetCapacityError::ZeroCapacity => f.write_str("changing the capacity of a bounded channel to 0"),
+            }
+        }
+    }
+
+    impl Error for SetCapacityError {}
+
+    impl fmt::Display for WaitCapacityError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                WaitCapacityError::Unreachable => f.write_str("waiting for more room than the capacity of the channel"),
+                WaitCapacityError::Disconnected => f.write_str("waiting for room on a channel with no receiver left"),
+            }
+        }
+    }
+
+    impl Error for WaitCapacityError {}
+
+    impl fmt::Display for RecvTimeoutError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                RecvTimeoutError::Timeout => f.write_str("timed out receiving on an empty channel"),
+                RecvTimeoutError::Disconnected => f.write_str("receiving on an empty channel with no sender left"),
+            }
+        }
+    }
+
+    impl Error for RecvTimeoutError {}
+
+    impl<T> Receiver<T> {
+        
+        /// bogus implementation of recv that would hang forever, in the case that there is no msg to receive from the 
+        /// channel and all the senders have been dropped where there is no point to block waiting for msg any more
+        pub fn tx_unaware_recv(&self) -> T {
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            loop {
+                shared_mut_data_guard = self.skip_expired(shared_mut_data_guard);
+                if let Some(msg) = shared_mut_data_guard.pop_msg() {
+                    self.slots_freed(shared_mut_data_guard, 1);
+                    return msg;
+                }
+                // core implementation to enable the receive to become a blocking call in this case
+                // when there is no current msg to receive from the channel is to meke use of the cond var that
+                // is meant to notify the presence of a new sent msg s.t. the cond var is waited atomically with 
+                // the release of lock held on this receiving end right now, and reacquiring the lock on the
+                // cond var's notification to proceed into the next round of the loop, where the sent msg would be returned
+                shared_mut_data_guard = self.shared_inner.wait_for_msg(shared_mut_data_guard, None);
+            }
+        }
+
+        pub fn recv(&self) -> Result<T, NoMoreSenderErr> {
+            self.recv_queued(false).map(|queued| queued.msg)
+        }
+
+        /// recv for at-least-once delivery, where the msg comes in a guard to be acked once it's been
+        /// dealt with. a guard dropped without an ack, e.g. on a panic of the consumer, puts the msg
+        /// back at the front of the queue for another go, under its own sequence number, and counted
+        /// as received only the once. until then, the msg keeps its slot in a bounded channel, s.t.
+        /// the senders can't fill the queue past its capacity meanwhile
+        ///
+        /// on a rendezvous channel, the sender is done once the msg is taken, as for a plain recv,
+        /// and the next msg can't be sent until the delivery is settled one way or the other
+        pub fn recv_delivery(&self) -> Result<Delivery<'_, T>, NoMoreSenderErr> {
+            self.recv_queued(true).map(|queued| Delivery {
+                queued: Some(queued),
+                receiver: self,
+            })
+        }
+
+        /// recv along with the sequence number of the msg, which is its place in the order msgs were
+        /// sent on this channel by all the senders, counting from 0. the msgs are received in the
+        /// order of their sequence numbers, which a consumer can check for gaps, e.g. msgs lost to
+        /// the overflow policy or to their ttl
+        ///
+        /// # Panics
+        ///
+        /// if the channel isn't sequenced, see ChannelBuilder::sequenced
+        pub fn recv_with_seq(&self) -> Result<(u64, T), NoMoreSenderErr> {
+            assert!(self.shared_inner.sequenced, "recv_with_seq on a channel that isn't sequenced");
+            self.recv_queued(false).map(|queued| (queued.seq.unwrap(), queued.msg))
+        }
+
+        // the blocking recv behind recv, recv_with_seq and recv_delivery, where a msg taken for a delivery
+        // keeps its slot until it's settled. the pickup is still news to the sender waiting for it on
+        // a rendezvous channel, and to the observer, hence slots_freed all the same
+        fn recv_queued(&self, for_delivery: bool) -> Result<QueuedMsg<T>, NoMoreSenderErr> {
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            loop {
+                shared_mut_data_guard = self.skip_expired(shared_mut_data_guard);
+                if let Some(queued) = shared_mut_data_guard.pop_queued() {
+                    let freed_cnt = if for_delivery {
+                        shared_mut_data_guard.unacked_cnt += 1;
+                        0
+                    } else {
+                        1
+                    };
+                    self.slots_freed(shared_mut_data_guard, freed_cnt);
+                    return Ok(queued);
+                } else {
+                    // here in the `else` branch due to the fact that the exucution of the call finds out that
+                    // there is no data in the channel to receive, further divided in two cases
+                    if shared_mut_data_guard.is_recv_closed() {
+                        // in case that there is no sender in the mpsc setup (or the channel is closed), plus
+                        // no msg to receive from the channel
+                        return Err(NoMoreSenderErr)
+                    } else {
+                        // otherwise the receive becomes a blocking call that proceeds when further msg is sent by any sender
+                        shared_mut_data_guard = self.shared_inner.wait_for_msg(shared_mut_data_guard, None);
+                    }
+                }
+            }
+        }
+
+        /// non-blocking counterpart of recv that returns right away when there is no msg to receive,
+        /// so the channel can be polled as part of some other event loop
+        pub fn try_recv(&self) -> Result<T, TryRecvError> {
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            shared_mut_data_guard = self.skip_expired(shared_mut_data_guard);
+            match shared_mut_data_guard.pop_msg() {
+                Some(msg) => {
+                    self.slots_freed(shared_mut_data_guard, 1);
+                    Ok(msg)
+                },
+                None if shared_mut_data_guard.is_recv_closed() => Err(TryRecvError::Disconnected),
+                None => Err(TryRecvError::Empty),
+            }
+        }
+
+        /// see Sender::close
+        pub fn close(&self) {
+            self.shared_inner.close();
+        }
+
+        /// holds off the senders, bounded channel or not, as if the queue was full, s.t. the sends block
+        /// or else do what the overflow policy of the channel says, until resume is called. the msgs
+        /// already queued up can still be received in the meantime
+        pub fn pause(&self) {
+            self.shared_inner.set_paused(true);
+        }
+
+        /// lets the senders held off by pause go on
+        pub fn resume(&self) {
+            self.shared_inner.set_paused(false);
+        }
+
+        pub fn is_paused(&self) -> bool {
+            self.shared_inner.inner_mut_data.lock().unwrap().paused
+        }
+
+        /// see Sender::same_channel
+        pub fn same_channel(&self, other: &Receiver<T>) -> bool {
+            Arc::ptr_eq(&self.shared_inner, &other.shared_inner)
+        }
+
+        /// whether every recv from now on is bound to fail, the senders being all gone or the channel
+        /// closed, with no msg left in the queue
+        pub fn is_closed(&self) -> bool {
+            let shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            shared_mut_data_guard.is_recv_closed() && shared_mut_data_guard.msg_queue.is_empty()
+        }
+
+        /// see Sender::name
+        pub fn name(&self) -> Option<&str> {
+            self.shared_inner.name.as_deref()
+        }
+
+        /// see Sender::sender_count, none meaning no msg is coming any more but for those queued up
+        pub fn sender_count(&self) -> usize {
+            self.shared_inner.inner_mut_data.lock().unwrap().sender_cnt
+        }
+
+        /// see Sender::receiver_count, this receiver included
+        pub fn receiver_count(&self) -> usize {
+            self.shared_inner.inner_mut_data.lock().unwrap().receiver_cnt
+        }
+
+        /// see Sender::stats
+        pub fn stats(&self) -> ChannelStats {
+            self.shared_inner.inner_mut_data.lock().unwrap().stats()
+        }
+
+        /// see Sender::len
+        pub fn len(&self) -> usize {
+            self.shared_inner.inner_mut_data.lock().unwrap().msg_queue.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// see Sender::capacity
+        pub fn capacity(&self) -> Option<usize> {
+            self.shared_inner.inner_mut_data.lock().unwrap().capacity
+        }
+
+        /// see Sender::set_capacity
+        pub fn set_capacity(&self, capacity: usize) -> Result<(), SetCapacityError> {
+            self.shared_inner.set_capacity(capacity)
+        }
+
+        /// async counterpart of recv, whose returned future resolves the same way recv returns, but
+        /// instead of blocking the thread while there is no msg, the task is parked with its waker
+        /// stored in the channel for a sender to wake up later
+        pub fn recv_async(&self) -> RecvFuture<'_, T> {
+            RecvFuture {
+                receiver: self,
+                registered_waker: None,
+            }
+        }
+
+        // the polling logic behind the async APIs of the receiving end, resolving the same way recv
+        // returns, or else registering the waker of the task to be woken up by the next send. a
+        // recv_async future tells whether it was parked already, to keep count of the parked tasks,
+        // which the Stream, having no way to tell it's dropped, is left out of
+        fn poll_recv(&self, cx: &mut Context<'_>, parked: Option<bool>) -> Poll<Result<T, NoMoreSenderErr>> {
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            shared_mut_data_guard = self.skip_expired(shared_mut_data_guard);
+            let was_parked = parked == Some(true);
+            if let Some(msg) = shared_mut_data_guard.pop_msg() {
+                if was_parked {
+                    shared_mut_data_guard.parked_task_cnt -= 1;
+                }
+                self.slots_freed(shared_mut_data_guard, 1);
+                return Poll::Ready(Ok(msg));
+            }
+            if shared_mut_data_guard.is_recv_closed() {
+                if was_parked {
+                    shared_mut_data_guard.parked_task_cnt -= 1;
+                }
+                return Poll::Ready(Err(NoMoreSenderErr));
+            }
+            if parked == Some(false) {
+                shared_mut_data_guard.parked_task_cnt += 1;
+            }
+            // the check and the registration of the waker happen under the same lock that a sender
+            // needs to push a msg, so a msg can't slip in between unnoticed. being polled again
+            // without having been woken up, the task would find its waker already registered
+            let waker = cx.waker();
+            if !shared_mut_data_guard.recv_wakers.iter().any(|registered| registered.will_wake(waker)) {
+                shared_mut_data_guard.recv_wakers.push_back(waker.clone());
+            }
+            Poll::Pending
+        }
+
+        // common tail of all the flavors of recv once msgs are taken out of the queue, which frees up
+        // slots that senders blocked on a full bounded channel may be waiting for, be it threads
+        // blocked in a send call or async tasks waiting for the sink to become ready
+        fn slots_freed(&self, mut shared_mut_data_guard: MutexGuard<'_, SharedInnerMut<T>>, freed_cnt: usize) {
+            let waker_cnt = freed_cnt.min(shared_mut_data_guard.send_wakers.len());
+            let send_wakers: Vec<Waker> = shared_mut_data_guard.send_wakers.drain(..waker_cnt).collect();
+            let is_rendezvous = shared_mut_data_guard.is_rendezvous();
+            // a sender in wait_capacity may be woken up for too few free slots to go on, and go back
+            // to waiting with the wakeup that a sender blocked in a send was waiting for
+            let capacity_waited_for = shared_mut_data_guard.capacity_waiter_cnt > 0;
+            let recv_events = std::mem::take(&mut shared_mut_data_guard.recv_events);
+            // a receiver woken up for a whole send_batch passes the wakeup on as it leaves msgs behind,
+            // to one more thread blocked in a recv call and one more task parked on a recv_async
+            // future, if any. a receiver still spinning has no wakeup to miss, as it counts itself
+            // among the waiting ones before it blocks, under the lock
+            let relay_wakeup = !shared_mut_data_guard.msg_queue.is_empty() && shared_mut_data_guard.waiting_receiver_cnt > 0;
+            let relay_waker = if shared_mut_data_guard.msg_queue.is_empty() {
+                None
+            } else {
+                shared_mut_data_guard.recv_wakers.pop_front()
+            };
+            #[cfg(feature = "tracing")]
+            let taken_cnt = shared_mut_data_guard.taken_cnt;
+            drop(shared_mut_data_guard);
+            // expired msgs count among the slots freed, though not among the msgs taken
+            #[cfg(feature = "tracing")]
+            tracing::trace!(channel = self.shared_inner.name.as_deref(), taken_cnt, msg_cnt = freed_cnt, "msgs taken");
+            // on a rendezvous channel, the senders blocked for a free slot and the one sender waiting
+            // for the pickup of its msg all wait on the same cond var, and it's the latter that has to
+            // be woken up for sure
+            if is_rendezvous || freed_cnt > 1 || capacity_waited_for {
+                self.shared_inner.send_wakeup_flag.notify_all();
+            } else {
+                self.shared_inner.send_wakeup_flag.notify_one();
+            }
+            send_wakers.into_iter().for_each(Waker::wake);
+            if relay_wakeup {
+                self.shared_inner.recv_wakeup_flag.notify_one();
+            }
+            relay_waker.into_iter().for_each(Waker::wake);
+            if let Some(observer) = &self.shared_inner.observer {
+                recv_events.into_iter().for_each(|(seq, queued_for)| observer.on_recv(seq, queued_for));
+            }
+        }
+
+        // every flavor of recv drops the msgs that have outlived the ttl of the channel before taking
+        // any msg out of the queue. the slots freed that way are made known to the senders right
+        // away, as there may be no msg left to take, with the receiver about to wait in turn
+        fn skip_expired<'a>(&'a self, mut shared_mut_data_guard: MutexGuard<'a, SharedInnerMut<T>>) -> MutexGuard<'a, SharedInnerMut<T>> {
+            let expired_cnt = shared_mut_data_guard.purge_expired();
+            if expired_cnt == 0 {
+                return shared_mut_data_guard;
+            }
+            self.slots_freed(shared_mut_data_guard, expired_cnt);
+            self.shared_inner.inner_mut_data.lock().unwrap()
+        }
+
+        /// batched counterpart of recv, which blocks the same way for a first msg to arrive, but then
+        /// moves up to `limit` msgs into the given buffer under the same acquisition of the lock,
+        /// returning how many
+        ///
+        /// # Panics
+        ///
+        /// if limit is 0, which would never take a msg, and spin the loop of a caller draining the
+        /// channel until recv_many fails
+        pub fn recv_many(&self, buffer: &mut Vec<T>, limit: usize) -> Result<usize, NoMoreSenderErr> {
+            assert!(limit > 0, "recv_many with a limit of 0");
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            loop {
+                shared_mut_data_guard = self.skip_expired(shared_mut_data_guard);
+                if !shared_mut_data_guard.msg_queue.is_empty() {
+                    let taken_cnt = limit.min(shared_mut_data_guard.msg_queue.len());
+                    buffer.extend(shared_mut_data_guard.drain_queued(taken_cnt).into_iter().map(|queued| queued.msg));
+                    self.slots_freed(shared_mut_data_guard, taken_cnt);
+                    return Ok(taken_cnt);
+                }
+                if shared_mut_data_guard.is_recv_closed() {
+                    return Err(NoMoreSenderErr);
+                }
+                shared_mut_data_guard = self.shared_inner.wait_for_msg(shared_mut_data_guard, None);
+            }
+        }
+
+        /// takes the whole backlog of msgs currently queued up at once, under a single acquisition of
+        /// the lock, rather than one acquisition per msg with repeated try_recv calls
+        pub fn drain(&self) -> Vec<T> {
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            shared_mut_data_guard = self.skip_expired(shared_mut_data_guard);
+            let backlog: Vec<T> = shared_mut_data_guard.drain_queued(usize::MAX).into_iter().map(|queued| queued.msg).collect();
+            if !backlog.is_empty() {
+                self.slots_freed(shared_mut_data_guard, backlog.len());
+            }
+            backlog
+        }
+
+        /// iterator over the msgs currently queued up in the channel that never blocks, ending as soon
+        /// as the queue is found empty, e.g. for draining the channel once per tick of a loop
+        pub fn try_iter(&self) -> ReceiverTryIter<'_, T> {
+            ReceiverTryIter {
+                receiver: self,
+            }
+        }
+
+        /// blocking recv that gives up waiting for a msg once the given timeout has elapsed
+        pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            let shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            // a timeout too long for an Instant to reach, e.g. Duration::MAX, is no timeout at all
+            let deadline = shared_mut_data_guard.clock.now().checked_add(timeout);
+            self.recv_until(shared_mut_data_guard, deadline)
+        }
+
+        /// blocking recv that gives up waiting for a msg once the given point in time is reached,
+        /// handy when several channels are waited on in turn against one common deadline. the point
+        /// in time is the one of the clock of the channel, see ChannelBuilder::clock
+        pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+            self.recv_until(self.shared_inner.inner_mut_data.lock().unwrap(), Some(deadline))
+        }
+
+        // a blocking recv with no deadline at all for None, which never times out
+        fn recv_until<'a>(&'a self, mut shared_mut_data_guard: MutexGuard<'a, SharedInnerMut<T>>, deadline: Option<Instant>) -> Result<T, RecvTimeoutError> {
+            loop {
+                shared_mut_data_guard = self.skip_expired(shared_mut_data_guard);
+                if let Some(msg) = shared_mut_data_guard.pop_msg() {
+                    self.slots_freed(shared_mut_data_guard, 1);
+                    return Ok(msg);
+                }
+                if shared_mut_data_guard.is_recv_closed() {
+                    return Err(RecvTimeoutError::Disconnected);
+                }
+                // the cond var can wake up spuriously or on a msg that another party raced to take,
+                // so the remaining time is recomputed against the fixed deadline on every round
+                if shared_mut_data_guard.is_past(deadline) {
+                    return Err(RecvTimeoutError::Timeout);
+                }
+                shared_mut_data_guard = self.shared_inner.wait_for_msg(shared_mut_data_guard, deadline);
+            }
+        }
+    }
+    
+    /// with the `futures` feature, the receiving end doubles as a Stream of msgs, ending once all the
+    /// senders are gone and the queue is drained, to compose with the StreamExt combinators
+    #[cfg(feature = "futures")]
+    impl<T> futures::Stream for Receiver<T> {
+        type Item = T;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.poll_recv(cx, None).map(Result::ok)
+        }
+    }
+
+    /// with the `futures` feature, the sending end doubles as a Sink of msgs, where readiness
+    /// reflects the capacity of a bounded channel. a msg that can't be sent for want of receivers is
+    /// handed back in the error, which carries no value when it's poll_ready that finds out. a full
+    /// channel with an overflow policy dropping msgs is always ready, with the policy applied by
+    /// start_send, whereas the Fail policy waits for room as Block does, as send does
+    #[cfg(feature = "futures")]
+    impl<T> futures::Sink<T> for Sender<T> {
+        type Error = NoMoreReceiverErr<Option<T>>;
+
+        fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            if shared_mut_data_guard.is_send_closed() {
+                return Poll::Ready(Err(NoMoreReceiverErr(None)));
+            }
+            let drops_msgs = matches!(shared_mut_data_guard.overflow_policy, OverflowPolicy::DropNewest | OverflowPolicy::DropOldest);
+            if !shared_mut_data_guard.is_full() || drops_msgs {
+                return Poll::Ready(Ok(()));
+            }
+            let waker = cx.waker();
+            if !shared_mut_data_guard.send_wakers.iter().any(|registered| registered.will_wake(waker)) {
+                shared_mut_data_guard.send_wakers.push_back(waker.clone());
+            }
+            Poll::Pending
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+            let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+            if shared_mut_data_guard.is_send_closed() {
+                return Err(NoMoreReceiverErr(Some(item)));
+            }
+            if shared_mut_data_guard.is_full() {
+                match shared_mut_data_guard.on_full() {
+                    OnFull::Wait | OnFull::Proceed | OnFull::Reject => {},
+                    OnFull::Discard => return Ok(()),
+                }
+            }
+            self.enqueue(shared_mut_data_guard, item);
+            Ok(())
+        }
+
+        // a msg is visible to the receivers as soon as start_send returns, with nothing buffered on
+        // the sending end to flush
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    // modelling the ONE common entity shared (by means of Arc pointer) among the sender(s) and the one receiver
+    // in a mpsc setting
+    struct SharedInner<T> {
+        inner_mut_data: Mutex<SharedInnerMut<T>>,
+        recv_wakeup_flag: Condvar,
+        // the counterpart of the cond var above for the sending end, only ever waited on by senders
+        // of a bounded channel when the queue is at capacity
+        send_wakeup_flag: Condvar,
+        // set once and for all by ChannelBuilder::name, hence kept out of the mutex
+        name: Option<String>,
+        // see ChannelBuilder::spin
+        spin_rounds: u32,
+        // see ChannelBuilder::sequenced
+        sequenced: bool,
+        // see ChannelBuilder::observer
+        observer: Option<std::sync::Arc<dyn ChannelObserver>>,
+        // what the clock of the channel wakes the waiting threads by, see Clock::subscribe
+        clock_hook: std::sync::Arc<ClockHook>,
+    }
+
+    impl<T> SharedInner<T> {
+        // the counterpart of Receiver::slots_freed, letting as many waiting receivers know as there
+        // are msgs newly pushed into the queue
+        fn msgs_enqueued(&self, shared_mut_data_guard: MutexGuard<'_, SharedInnerMut<T>>, enqueued_cnt: usize) {
+            self.wake_receivers(shared_mut_data_guard, enqueued_cnt, enqueued_cnt);
+        }
+
+        // msgs_enqueued for a send_batch, which lets a single waiting receiver know however many msgs
+        // there are, for it to pass the wakeup on, see Receiver::slots_freed
+        fn batch_enqueued(&self, shared_mut_data_guard: MutexGuard<'_, SharedInnerMut<T>>, enqueued_cnt: usize) {
+            self.wake_receivers(shared_mut_data_guard, enqueued_cnt, 1);
+        }
+
+        fn wake_receivers(&self, mut shared_mut_data_guard: MutexGuard<'_, SharedInnerMut<T>>, enqueued_cnt: usize, wakeup_cnt: usize) {
+            let waker_cnt = wakeup_cnt.min(shared_mut_data_guard.recv_wakers.len());
+            let recv_wakers: Vec<Waker> = shared_mut_data_guard.recv_wakers.drain(..waker_cnt).collect();
+            // the msgs just pushed are the last ones sent
+            let sent_cnt = shared_mut_data_guard.sent_cnt;
+            // dropping the lock guard to release the lock before notifying the receiving end
+            drop(shared_mut_data_guard);
+            #[cfg(feature = "tracing")]
+            tracing::trace!(channel = self.name.as_deref(), first_seq = sent_cnt - enqueued_cnt as u64, msg_cnt = enqueued_cnt, "msgs sent");
+            if wakeup_cnt > 1 {
+                self.recv_wakeup_flag.notify_all();
+            } else {
+                self.recv_wakeup_flag.notify_one();
+            }
+            recv_wakers.into_iter().for_each(Waker::wake);
+            if let Some(observer) = &self.observer {
+                (sent_cnt - enqueued_cnt as u64..sent_cnt).for_each(|seq| observer.on_send(seq));
+            }
+        }
+
+        // all the blocking calls of the receiving end wait for a msg through here, with or without
+        // a deadline, s.t. the books on blocked receivers and blocking waits are kept in one place.
+        // the wait ends early, for the caller to look again, once the deadline is past
+        fn wait_for_msg<'a>(
+            &'a self,
+            mut shared_mut_data_guard: MutexGuard<'a, SharedInnerMut<T>>,
+            deadline: Option<Instant>,
+        ) -> MutexGuard<'a, SharedInnerMut<T>> {
+            let msg_pending = |shared_mut_data: &SharedInnerMut<T>| {
+                shared_mut_data.msg_queue.is_empty() && !shared_mut_data.is_recv_closed()
+            };
+            let mut shared_mut_data_guard = self.spin_while(shared_mut_data_guard, msg_pending, deadline);
+            if !msg_pending(&shared_mut_data_guard) || shared_mut_data_guard.is_past(deadline) {
+                return shared_mut_data_guard;
+            }
+            shared_mut_data_guard.waiting_receiver_cnt += 1;
+            shared_mut_data_guard.stats.blocking_waits += 1;
+            let timeout = shared_mut_data_guard.wait_timeout(deadline);
+            #[cfg(feature = "tracing")]
+            let _wait_span = tracing::debug_span!("recv_wait", channel = self.name.as_deref(), ?timeout).entered();
+            let mut shared_mut_data_guard = match timeout {
+                None => self.recv_wakeup_flag.wait(shared_mut_data_guard).unwrap(),
+                Some(timeout) => self.recv_wakeup_flag.wait_timeout(shared_mut_data_guard, timeout).unwrap().0,
+            };
+            shared_mut_data_guard.waiting_receiver_cnt -= 1;
+            shared_mut_data_guard
+        }
+
+        // the counterpart of wait_for_msg for the sending end, be it waiting for a free slot in a
+        // bounded channel, see SharedInnerMut::slot_pending, or for the pickup of the msg in a
+        // rendezvous channel, as told by `pending`
+        fn wait_for_slot<'a>(
+            &'a self,
+            mut shared_mut_data_guard: MutexGuard<'a, SharedInnerMut<T>>,
+            deadline: Option<Instant>,
+            pending: impl Fn(&SharedInnerMut<T>) -> bool,
+        ) -> MutexGuard<'a, SharedInnerMut<T>> {
+            let mut shared_mut_data_guard = self.spin_while(shared_mut_data_guard, &pending, deadline);
+            if !pending(&shared_mut_data_guard) || shared_mut_data_guard.is_past(deadline) {
+                return shared_mut_data_guard;
+            }
+            shared_mut_data_guard.stats.blocking_waits += 1;
+            let timeout = shared_mut_data_guard.wait_timeout(deadline);
+            #[cfg(feature = "tracing")]
+            let _wait_span = tracing::debug_span!("send_wait", channel = self.name.as_deref(), ?timeout).entered();
+            match timeout {
+                None => self.send_wakeup_flag.wait(shared_mut_data_guard).unwrap(),
+                Some(timeout) => self.send_wakeup_flag.wait_timeout(shared_mut_data_guard, timeout).unwrap().0,
+            }
+        }
+
+        // the spinning phase ahead of a blocking wait, see ChannelBuilder::spin, releasing the lock in
+        // between rounds of busy waiting that get longer and longer, until whatever the caller is
+        // waiting for is no longer pending, the rounds run out, or the deadline is past
+        fn spin_while<'a>(
+            &'a self,
+            mut shared_mut_data_guard: MutexGuard<'a, SharedInnerMut<T>>,
+            pending: impl Fn(&SharedInnerMut<T>) -> bool,
+            deadline: Option<Instant>,
+        ) -> MutexGuard<'a, SharedInnerMut<T>> {
+            for round in 0..self.spin_rounds {
+                if !pending(&shared_mut_data_guard) || shared_mut_data_guard.is_past(deadline) {
+                    break;
+                }
+                drop(shared_mut_data_guard);
+                // past a few rounds of doubling the busy wait, the other threads are more likely to
+                // get somewhere with the cpu handed over to them
+                if round < 6 {
+                    (0..1 << round).for_each(|_| std::hint::spin_loop());
+                } else {
+                    sync::thread::yield_now();
+                }
+                shared_mut_data_guard = self.inner_mut_data.lock().unwrap();
+            }
+            shared_mut_data_guard
+        }
+
+        fn set_capacity(&self, capacity: usize) -> Result<(), SetCapacityError> {
+            let mut shared_mut_data_guard = self.inner_mut_data.lock().unwrap();
+            if shared_mut_data_guard.capacity.is_none_or(|capacity| capacity == 0) {
+                return Err(SetCapacityError::FixedCapacity);
+            }
+            if capacity == 0 {
+                return Err(SetCapacityError::ZeroCapacity);
+            }
+            shared_mut_data_guard.capacity = Some(capacity);
+            let send_wakers = std::mem::take(&mut shared_mut_data_guard.send_wakers);
+            drop(shared_mut_data_guard);
+            // every sender blocked may well fit into the grown queue, and those that don't just wait
+            // again after checking. a sender in wait_capacity finds out whether the room it waits
+            // for is still to be had at all
+            self.send_wakeup_flag.notify_all();
+            send_wakers.into_iter().for_each(Waker::wake);
+            Ok(())
+        }
+
+        fn set_paused(&self, paused: bool) {
+            let mut shared_mut_data_guard = self.inner_mut_data.lock().unwrap();
+            shared_mut_data_guard.paused = paused;
+            if paused {
+                return;
+            }
+            let send_wakers = std::mem::take(&mut shared_mut_data_guard.send_wakers);
+            drop(shared_mut_data_guard);
+            // as with a grown capacity, every sender held off may go on now
+            self.send_wakeup_flag.notify_all();
+            send_wakers.into_iter().for_each(Waker::wake);
+        }
+
+        fn close(&self) {
+            let mut shared_mut_data_guard = self.inner_mut_data.lock().unwrap();
+            shared_mut_data_guard.closed = true;
+            let recv_wakers = std::mem::take(&mut shared_mut_data_guard.recv_wakers);
+            let send_wakers = std::mem::take(&mut shared_mut_data_guard.send_wakers);
+            drop(shared_mut_data_guard);
+            #[cfg(feature = "tracing")]
+            tracing::debug!(channel = self.name.as_deref(), "channel closed");
+            // everyone waiting on either end has to find out, just like on the drop of the last sender
+            // and of the last receiver at once
+            self.recv_wakeup_flag.notify_all();
+            self.send_wakeup_flag.notify_all();
+            recv_wakers.into_iter().for_each(Waker::wake);
+            send_wakers.into_iter().for_each(Waker::wake);
+        }
+
+    }
+
+    impl<T: Send> ClockWaiter for SharedInner<T> {
+        // the clock of the channel moved on, which may have run out the timeout of any thread waiting.
+        // the lock is taken and let go of first, for a thread that looked at the clock before it moved
+        // on, under the lock, to be in its wait by the time it's notified
+        fn wake(&self) {
+            drop(self.inner_mut_data.lock());
+            self.recv_wakeup_flag.notify_all();
+            self.send_wakeup_flag.notify_all();
+        }
+    }
+
+    // modelling the data parts, within the the common entity as above, that both sender(s) and receiver parties
+    // would mutate, synchronized by Mutex in this implementation
+    struct SharedInnerMut<T> {
+        msg_queue: SegmentedQueue<QueuedMsg<T>>,
+        // these fields are to keep a correct account of number of senders and receivers
+        // in the mpmc setup. the reference count to the common shared entity of the channel cannot naively
+        // give the accurate account, for example, when querying whether there is any senders left, a reference
+        // count of 1 wouldn't tell whether that's 1 sender or receiver left alive
+        sender_cnt: usize,
+        receiver_cnt: usize,
+        // max number of msgs the queue may hold before sends start blocking, None for an unbounded channel
+        // and Some(0) for a rendezvous channel
+        capacity: Option<usize>,
+        // what a send does once a bounded queue is at capacity, always Block for a rendezvous channel
+        overflow_policy: OverflowPolicy,
+        // how long a msg stays worth receiving after it's sent, None for msgs that never go stale
+        ttl: Option<Duration>,
+        // running totals of msgs that have gone into the queue, and of those taken out of it by a
+        // receiver, leaving out the expired ones, by which a sender on a rendezvous channel can tell
+        // whether its msg has been picked up
+        sent_cnt: u64,
+        taken_cnt: u64,
+        // number of receivers blocked in a recv call right now
+        waiting_receiver_cnt: usize,
+        // number of tasks parked on a recv_async future right now, woken up yet or not
+        parked_task_cnt: usize,
+        // number of senders blocked in Sender::wait_capacity right now
+        capacity_waiter_cnt: usize,
+        // number of msgs out on a Delivery not settled yet, each keeping its slot in the queue
+        unacked_cnt: usize,
+        // the id of the next Sender, see Sender::id
+        next_sender_id: u64,
+        // sequence number of the msg of each sender last taken out of the queue, only there to check
+        // the order of a sequenced channel in a debug build
+        last_taken_seqs: HashMap<u64, u64>,
+        // counters behind Sender::stats and Receiver::stats, where the sent and received counts are
+        // the sent_cnt and taken_cnt above
+        stats: ChannelStats,
+        // set by an explicit close on either end, after which the channel behaves as if all the
+        // senders and all the receivers were gone, but for the msgs already queued up
+        closed: bool,
+        // set by Receiver::pause, for the sends to find the channel full until Receiver::resume
+        paused: bool,
+        // whether there is a ChannelObserver, which needs the msgs timed on their way through the queue
+        observed: bool,
+        // see ChannelBuilder::sequenced
+        sequenced: bool,
+        // the time source of the ttl and the timeouts, kept under the lock with the msgs it times
+        clock: std::sync::Arc<dyn Clock>,
+        // the msgs taken out of the queue since the observer was last told, with their time spent
+        // in the queue, for the observer to be told once the lock is released
+        recv_events: Vec<(u64, Duration)>,
+        // the async counterparts of the two cond vars, where a task awaiting a msg, or awaiting
+        // capacity in a bounded channel, leaves its waker
+        recv_wakers: VecDeque<Waker>,
+        send_wakers: VecDeque<Waker>,
+    }
+
+    impl<T> SharedInnerMut<T> {
+        // provide utility to intialize such structured, ready to be called by public-facing API for creating new channel
+        fn new(builder: &ChannelBuilder) -> Self {
+            Self {
+                msg_queue: SegmentedQueue::new(builder.segment_size),
+                sender_cnt: 1,
+                receiver_cnt: 1,
+                capacity: builder.capacity,
+                overflow_policy: builder.overflow_policy,
+                ttl: builder.ttl,
+                sent_cnt: 0,
+                taken_cnt: 0,
+                waiting_receiver_cnt: 0,
+                parked_task_cnt: 0,
+                capacity_waiter_cnt: 0,
+                unacked_cnt: 0,
+                next_sender_id: 1,
+                last_taken_seqs: HashMap::new(),
+                stats: ChannelStats::default(),
+                closed: false,
+                paused: false,
+                observed: builder.observer.is_some(),
+                sequenced: builder.sequenced,
+                clock: std::sync::Arc::clone(&builder.clock),
+                recv_events: Vec::new(),
+                recv_wakers: VecDeque::new(),
+                send_wakers: VecDeque::new(),
+            }
+        }
+
+        // a rendezvous channel still needs room for one msg in flight, handed over from the sender
+        // to the receiver through the queue. a paused channel is as good as full, whatever its capacity
+        fn is_full(&self) -> bool {
+            self.paused || self.capacity.is_some_and(|capacity| self.taken_slots() >= capacity.max(1))
+        }
+
+        // room left in the queue, where a rendezvous channel has room for its one msg in flight
+        fn free_slots(&self) -> usize {
+            if self.paused {
+                return 0;
+            }
+            self.capacity.map_or(usize::MAX, |capacity| capacity.max(1).saturating_sub(self.taken_slots()))
+        }
+
+        // the msgs in the queue along with the ones out on a delivery, which may well come back
+        fn taken_slots(&self) -> usize {
+            self.msg_queue.len() + self.unacked_cnt
+        }
+
+        fn is_rendezvous(&self) -> bool {
+            self.capacity == Some(0)
+        }
+
+        // whether a receiver is there to take a msg handed over right now, see Sender::try_send
+        fn is_receiver_waiting(&self) -> bool {
+            self.waiting_receiver_cnt > 0 || self.parked_task_cnt > 0
+        }
+
+        // what a send waits for on a full queue, with the channel still open
+        fn slot_pending(&self) -> bool {
+            self.is_full() && !self.is_send_closed()
+        }
+
+        // whether the deadline, as told by the clock of the channel, has come, never for None
+        fn is_past(&self, deadline: Option<Instant>) -> bool {
+            deadline.is_some_and(|deadline| self.clock.now() >= deadline)
+        }
+
+        // the timeout of a blocking wait for the deadline, None for an untimed one, with no deadline
+        // or by a clock that wakes the waits up itself, see Clock::is_real_time
+        fn wait_timeout(&self, deadline: Option<Instant>) -> Option<Duration> {
+            let deadline = deadline.filter(|_| self.clock.is_real_time())?;
+            Some(deadline.saturating_duration_since(self.clock.now()))
+        }
+
+        // to be called by a send finding the queue full, where evicting the oldest msg to make room
+        // happens right here, under the same lock as the push of the new msg
+        fn on_full(&mut self) -> OnFull {
+            match self.overflow_policy {
+                OverflowPolicy::Block => OnFull::Wait,
+                OverflowPolicy::DropNewest => {
+                    self.stats.dropped += 1;
+                    OnFull::Discard
+                },
+                // with every slot kept by a delivery, there is no msg in the queue to make room
+                OverflowPolicy::DropOldest => {
+                    if self.msg_queue.pop_front().is_none() {
+                        return OnFull::Wait;
+                    }
+                    self.stats.dropped += 1;
+                    OnFull::Proceed
+                },
+                OverflowPolicy::Fail => OnFull::Reject,
+            }
+        }
+
+        // on a rendezvous channel, a msg not picked up by the time the sender gives up on the handoff is
+        // taken back out of the queue, where it's still the only msg, as if it had never been sent
+        fn retract_unhanded_msg(&mut self) -> T {
+            self.sent_cnt -= 1;
+            self.msg_queue.pop_back().unwrap().msg
+        }
+
+        fn new_sender_id(&mut self) -> u64 {
+            let id = self.next_sender_id;
+            self.next_sender_id += 1;
+            id
+        }
+
+        fn push_msg(&mut self, msg: T, sender_id: u64) {
+            // a ttl too long for an Instant to reach, e.g. Duration::MAX, never runs out
+            let expires_at = self.ttl.and_then(|ttl| self.clock.now().checked_add(ttl));
+            // the count of msgs sent so far is the sequence number of this one, which the observer
+            // is told of as well
+            let seq = (self.sequenced || self.observed).then_some(self.sent_cnt);
+            let sent_at = self.observed.then(|| self.clock.now());
+            self.msg_queue.push_back(QueuedMsg { msg, seq, sender_id, expires_at, sent_at, redelivered: false });
+            self.sent_cnt += 1;
+            self.record_depth();
+        }
+
+        // a msg out on a delivery given up on goes back where it was taken from, as the one msg
+        // received next, and isn't counted as taken once more when it is
+        fn requeue_msg(&mut self, queued: QueuedMsg<T>) {
+            self.msg_queue.push_front(QueuedMsg { redelivered: true, ..queued });
+        }
+
+        fn pop_msg(&mut self) -> Option<T> {
+            self.pop_queued().map(|queued| queued.msg)
+        }
+
+        fn pop_queued(&mut self) -> Option<QueuedMsg<T>> {
+            let queued = self.msg_queue.pop_front()?;
+            self.book_taken(&queued);
+            Some(queued)
+        }
+
+        // up to `cnt` msgs from the front of the queue, with their books kept as by pop_queued
+        fn drain_queued(&mut self, cnt: usize) -> Vec<QueuedMsg<T>> {
+            let drained: Vec<QueuedMsg<T>> = self.msg_queue.drain_front(cnt).collect();
+            drained.iter().for_each(|queued| self.book_taken(queued));
+            drained
+        }
+
+        // the books on a msg just taken out of the queue, where a msg redelivered has been counted
+        // and told of already, and comes back out of order, after the msgs taken meanwhile
+        fn book_taken(&mut self, queued: &QueuedMsg<T>) {
+            if queued.redelivered {
+                return;
+            }
+            // msgs leave the queue in the order they were sent, hence in the order of any one sender
+            // in particular, which is what every flavor of recv relies on, and what a sequenced
+            // channel checks in a debug build
+            if let Some(seq) = queued.seq.filter(|_| cfg!(debug_assertions) && self.sequenced) {
+                let last_taken_seq = self.last_taken_seqs.insert(queued.sender_id, seq);
+                debug_assert!(
+                    last_taken_seq.is_none_or(|last_taken_seq| last_taken_seq < seq),
+                    "msg {seq} of sender {} taken after its msg {last_taken_seq:?}",
+                    queued.sender_id,
+                );
+            }
+            self.taken_cnt += 1;
+            if let (Some(seq), Some(sent_at)) = (queued.seq, queued.sent_at) {
+                self.recv_events.push((seq, self.clock.now().saturating_duration_since(sent_at)));
+            }
+        }
+
+        // with every msg given the same ttl, msgs expire in the order they're queued up, so the
+        // expired ones are all at the front of the queue. returns how many were dropped
+        fn purge_expired(&mut self) -> usize {
+            if self.ttl.is_none() {
+                return 0;
+            }
+            let now = self.clock.now();
+            let mut expired_cnt = 0;
+            while self.msg_queue.front().is_some_and(|queued| queued.expires_at.is_some_and(|expires_at| expires_at <= now)) {
+                self.msg_queue.pop_front();
+                expired_cnt += 1;
+            }
+            self.stats.expired += expired_cnt as u64;
+            expired_cnt
+        }
+
+        fn record_depth(&mut self) {
+            self.stats.peak_depth = self.stats.peak_depth.max(self.msg_queue.len());
+        }
+
+        fn stats(&self) -> ChannelStats {
+            ChannelStats {
+                sent: self.sent_cnt,
+                received: self.taken_cnt,
+                ..self.stats
+            }
+        }
+
+        // whether a send is bound to fail, for want of a receiver
+        fn is_send_closed(&self) -> bool {
+            self.closed || self.receiver_cnt == 0
+        }
+
+        // whether a recv finding the queue empty is bound to fail, for want of a sender
+        fn is_recv_closed(&self) -> bool {
+            self.closed || self.sender_cnt == 0
+        }
+    }
+
+    // a msg in the queue, along with the point in time past which it's no longer worth receiving
+    struct QueuedMsg<T> {
+        msg: T,
+        // only stamped on a sequenced or observed channel
+        seq: Option<u64>,
+        sender_id: u64,
+        expires_at: Option<Instant>,
+        // only timed for a ChannelObserver
+        sent_at: Option<Instant>,
+        // set for a msg put back by a Delivery, which was taken once already
+        redelivered: bool,
+    }
+
+    /// hooks into the traffic of a channel, see ChannelBuilder::observer, for logging, metrics or
+    /// latency tracing to be plugged in from the outside. the hooks are called after the fact, on the
+    /// thread of the sender or the receiver, with the lock of the channel released
+    pub trait ChannelObserver: Send + Sync {
+        /// a msg went into the queue, known by its sequence number, see Receiver::recv_with_seq
+        fn on_send(&self, seq: u64) {}
+
+        /// a msg was taken out of the queue by a receiver, `queued_for` after it went in
+        fn on_recv(&self, seq: u64, queued_for: Duration) {}
+    }
+
+    /// for the observer to be read from while it's in use by the channel, e.g. for its metrics
+    impl<O: ChannelObserver + ?Sized> ChannelObserver for std::sync::Arc<O> {
+        fn on_send(&self, seq: u64) {
+            (**self).on_send(seq);
+        }
+
+        fn on_recv(&self, seq: u64, queued_for: Duration) {
+            (**self).on_recv(seq, queued_for);
+        }
+    }
+
+    /// the time source of a channel, see ChannelBuilder::clock
+    pub trait Clock: fmt::Debug + Send + Sync {
+        fn now(&self) -> Instant;
+
+        /// whether the clock moves on in real time, s.t. a blocking wait for a timeout told by it is
+        /// left to the OS to time. the waits by a clock that doesn't are untimed, to be woken up by
+        /// the clock itself whenever it moves on, through the hooks it's given by subscribe
+        fn is_real_time(&self) -> bool {
+            true
+        }
+
+        /// called once by every channel built with the clock, for a clock that isn't real time to
+        /// call ClockHook::wake on, every time it moves on, for as long as the hook is alive
+        fn subscribe(&self, hook: std::sync::Weak<ClockHook>) {}
+    }
+
+    /// the threads of one channel waiting on its clock, to be woken up by the clock once it moves on,
+    /// see Clock::subscribe
+    pub struct ClockHook {
+        // set once by ChannelBuilder::build, to the channel, which the hook doesn't keep alive
+        channel: std::sync::OnceLock<std::sync::Weak<dyn ClockWaiter>>,
+    }
+
+    // the SharedInner of a channel, whatever its T, as the ClockHook of the channel sees it
+    trait ClockWaiter: Send + Sync {
+        fn wake(&self);
+    }
+
+    impl ClockHook {
+        /// wakes the threads of the channel waiting with a timeout, for them to look at the clock
+        /// again, a no-op once the channel is gone
+        pub fn wake(&self) {
+            if let Some(channel) = self.channel.get().and_then(std::sync::Weak::upgrade) {
+                channel.wake();
+            }
+        }
+    }
+
+    impl fmt::Debug for ClockHook {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let live = self.channel.get().is_some_and(|channel| channel.strong_count() > 0);
+            f.debug_struct("ClockHook").field("live", &live).finish()
+        }
+    }
+
+    /// the real time of Instant::now
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct SystemClock;
+
+    impl Clock for SystemClock {
+        fn now(&self) -> Instant {
+            Instant::now()
+        }
+    }
+
+    /// a clock that only moves on by MockClock::advance, for the timeouts and the ttl of a channel to
+    /// be tested without sleeping through them. the clones of a MockClock all tell the same time,
+    /// s.t. the test can keep one to advance the clock of the channel with
+    #[derive(Debug, Clone)]
+    pub struct MockClock {
+        now: std::sync::Arc<std::sync::Mutex<Instant>>,
+        // the channels built with the clock, whose threads waiting on it are woken up by advance
+        hooks: std::sync::Arc<std::sync::Mutex<Vec<std::sync::Weak<ClockHook>>>>,
+    }
+
+    impl MockClock {
+        pub fn new() -> Self {
+            MockClock {
+                now: std::sync::Arc::new(std::sync::Mutex::new(Instant::now())),
+                hooks: std::sync::Arc::default(),
+            }
+        }
+
+        pub fn advance(&self, by: Duration) {
+            *self.now.lock().unwrap() += by;
+            // the hooks of the channels dropped since are let go of on the way
+            let hooks: Vec<_> = {
+                let mut hooks = self.hooks.lock().unwrap();
+                hooks.retain(|hook| hook.strong_count() > 0);
+                hooks.iter().filter_map(std::sync::Weak::upgrade).collect()
+            };
+            hooks.iter().for_each(|hook| hook.wake());
+        }
+    }
+
+    impl Default for MockClock {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+
+        fn is_real_time(&self) -> bool {
+            false
+        }
+
+        fn subscribe(&self, hook: std::sync::Weak<ClockHook>) {
+            self.hooks.lock().unwrap().push(hook);
+        }
+    }
+
+    /// configuration of a channel to build, for whatever goes beyond the plain channel and
+    /// sync_channel constructors, e.g.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use some_rust_examples::ch::tx_rx_channel::{ChannelBuilder, OverflowPolicy};
+    ///
+    /// let (tx, rx) = ChannelBuilder::new()
+    ///     .name("events")
+    ///     .capacity(64)
+    ///     .overflow_policy(OverflowPolicy::DropOldest)
+    ///     .ttl(Duration::from_secs(1))
+    ///     .build::<u32>();
+    /// ```
+    #[derive(Clone)]
+    pub struct ChannelBuilder {
+        name: Option<String>,
+        capacity: Option<usize>,
+        overflow_policy: OverflowPolicy,
+        ttl: Option<Duration>,
+        segment_size: usize,
+        spin_rounds: u32,
+        // the Arc of std rather than the one of the sync shim, which can't hold a trait object
+        observer: Option<std::sync::Arc<dyn ChannelObserver>>,
+        clock: std::sync::Arc<dyn Clock>,
+        sequenced: bool,
+    }
+
+    impl fmt::Debug for ChannelBuilder {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("ChannelBuilder")
+                .field("name", &self.name)
+                .field("capacity", &self.capacity)
+                .field("overflow_policy", &self.overflow_policy)
+                .field("ttl", &self.ttl)
+                .field("segment_size", &self.segment_size)
+                .field("spin_rounds", &self.spin_rounds)
+                .field("observed", &self.observer.is_some())
+                .field("clock", &self.clock)
+                .field("sequenced", &self.sequenced)
+                .finish()
+        }
+    }
+
+    impl Default for ChannelBuilder {
+        fn default() -> Self {
+            Self {
+                name: None,
+                capacity: None,
+                overflow_policy: OverflowPolicy::default(),
+                ttl: None,
+                segment_size: 32,
+                spin_rounds: 0,
+                observer: None,
+                clock: std::sync::Arc::new(SystemClock),
+                sequenced: false,
+            }
+        }
+    }
+
+    impl ChannelBuilder {
+        /// an unnamed, unbounded channel to begin with, whose msgs never go stale
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// a label for the channel to tell it apart from others, e.g. in logs, see Sender::name
+        pub fn name(mut self, name: impl Into<String>) -> Self {
+            self.name = Some(name.into());
+            self
+        }
+
+        /// makes the channel bounded, see sync_channel, with a zero capacity making it a rendezvous
+        pub fn capacity(mut self, capacity: usize) -> Self {
+            self.capacity = Some(capacity);
+            self
+        }
+
+        /// what a send does when the queue is full, see OverflowPolicy, with no effect on an unbounded channel
+        pub fn overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+            self.overflow_policy = overflow_policy;
+            self
+        }
+
+        /// makes the msgs go stale `ttl` after they're sent, s.t. a receiver catching up after a stall
+        /// skips the expired ones rather than acting on them, as counted by ChannelStats::expired
+        pub fn ttl(mut self, ttl: Duration) -> Self {
+            self.ttl = Some(ttl);
+            self
+        }
+
+        /// number of msgs per segment of the queue, which grows and shrinks by one segment at a time,
+        /// 32 unless set otherwise. a larger segment means fewer allocations on a busy channel, at
+        /// the cost of more memory held by a mostly idle one
+        pub fn segment_size(mut self, segment_size: usize) -> Self {
+            self.segment_size = segment_size;
+            self
+        }
+
+        /// makes a recv finding no msg, or a send finding no room, spin for up to `rounds` rounds
+        /// of exponential backoff before blocking on the cond var, which saves the round trip through
+        /// the OS scheduler when msgs come in microseconds apart, at the cost of burning cpu when
+        /// they don't. no spinning unless set
+        pub fn spin(mut self, rounds: u32) -> Self {
+            self.spin_rounds = rounds;
+            self
+        }
+
+        /// has `observer` told about every msg going into and out of the channel, see ChannelObserver
+        pub fn observer(mut self, observer: impl ChannelObserver + 'static) -> Self {
+            self.observer = Some(std::sync::Arc::new(observer));
+            self
+        }
+
+        /// the time source of the ttl, the timeouts and the deadlines of the channel, SystemClock
+        /// unless set otherwise, e.g. a MockClock for the timing of a test to be under its control
+        pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+            self.clock = std::sync::Arc::new(clock);
+            self
+        }
+
+        /// stamps every msg with a sequence number as it's sent, for the receivers to get along with
+        /// the msg from recv_with_seq. a debug build checks, msg by msg, that the msgs of every
+        /// sender are received in the order that sender sent them in. not sequenced unless set
+        pub fn sequenced(mut self) -> Self {
+            self.sequenced = true;
+            self
+        }
+
+        /// a rendezvous channel has no queue to overflow, hence a zero capacity only goes with the
+        /// Block overflow policy, and with no ttl, as a msg expiring on its way to the receiver would
+        /// leave its sender waiting for a pickup that never comes. the msgs are Send and 'static for
+        /// the clock of the channel to hold on to a hook into it, see Clock::subscribe, and to wake
+        /// its waiting threads from whichever thread the clock is moved on by
+        pub fn build<T: Send + 'static>(self) -> (Sender<T>, Receiver<T>) {
+            assert!(
+                self.capacity != Some(0) || self.overflow_policy == OverflowPolicy::Block,
+                "a rendezvous channel can only block on overflow"
+            );
+            assert!(self.capacity != Some(0) || self.ttl.is_none(), "a rendezvous channel can't have a ttl");
+            let new_shared_inner = Arc::new(SharedInner {
+                inner_mut_data: Mutex::new(SharedInnerMut::new(&self)),
+                recv_wakeup_flag: Condvar::new(),
+                send_wakeup_flag: Condvar::new(),
+                name: self.name,
+                spin_rounds: self.spin_rounds,
+                sequenced: self.sequenced,
+                observer: self.observer,
+                clock_hook: std::sync::Arc::new(ClockHook { channel: std::sync::OnceLock::new() }),
+            });
+            // loom's Arc comes without a Weak to hook the channel to its clock by, see sync
+            #[cfg(not(loom))]
+            {
+                let channel: std::sync::Weak<SharedInner<T>> = Arc::downgrade(&new_shared_inner);
+                new_shared_inner.clock_hook.channel.get_or_init(|| channel);
+                self.clock.subscribe(std::sync::Arc::downgrade(&new_shared_inner.clock_hook));
+            }
+
+            (
+                Sender { shared_inner: Arc::clone(&new_shared_inner), id: 0 },
+                Receiver { shared_inner: Arc::clone(&new_shared_inner) },
+            )
+        }
+    }
+
+    pub fn channel<T: Send + 'static>() -> (Sender<T>, Receiver<T>) {
+        ChannelBuilder::new().build()
+    }
+
+    /// bounded flavor of the channel, where at most `capacity` msgs can be queued up before
+    /// `Sender::send` blocks waiting for the receiver to drain some of them
+    ///
+    /// a zero capacity makes a rendezvous channel, where `send` blocks until a receiver has taken
+    /// the msg, making each send a synchronization point between the two threads
+    pub fn sync_channel<T: Send + 'static>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+        ChannelBuilder::new().capacity(capacity).build()
+    }
+}
+
+
+
+// the tests here run on real threads, and are left out of the loom builds, see loom_tests
+#[cfg(all(test, not(loom)))]
+mod tests{
+    use std::{thread, time::{Duration, Instant}};
+
+    use super::*;
+    #[test]
+    fn channel_only_channel_basic_send_recv() {
+        let test_channel = chennel_only_channel::Channel::<u32>::new();
+        assert!(test_channel.send(42).is_ok());
+        assert!(test_channel.send(43).is_ok());
+        assert_eq!(test_channel.recv(), Ok(42));
+        assert_eq!(test_channel.recv(), Ok(43));
+    }
+
+    #[test]
+    fn channel_only_channel_bounded_tx_blocks_while_full() {
+        let test_channel = chennel_only_channel::Channel::<u32>::with_capacity(1);
+        assert!(test_channel.send(42).is_ok());
+        thread::scope(|scope| {
+            let producer = scope.spawn(|| test_channel.send(43));
+            thread::sleep(Duration::from_millis(50));
+            assert!(!producer.is_finished());
+            assert_eq!(test_channel.recv(), Ok(42));
+            assert!(producer.join().unwrap().is_ok());
+        });
+        assert_eq!(test_channel.recv(), Ok(43));
+
+        // a producer blocked on the full channel finds out about the close too
+        assert!(test_channel.send(44).is_ok());
+        thread::scope(|scope| {
+            let producer = scope.spawn(|| test_channel.send(45));
+            thread::sleep(Duration::from_millis(50));
+            test_channel.close();
+            assert_eq!(producer.join().unwrap(), Err(45));
+        });
+    }
+
+    #[test]
+    fn channel_only_channel_close_wakes_rx() {
+        let test_channel = chennel_only_channel::Channel::<u32>::new();
+        thread::scope(|scope| {
+            let consumer = scope.spawn(|| std::iter::from_fn(|| test_channel.recv().ok()).collect::<Vec<_>>());
+            assert!(test_channel.send(42).is_ok());
+            thread::sleep(Duration::from_millis(50));
+            test_channel.close();
+            assert_eq!(consumer.join().unwrap(), vec![42]);
+        });
+        assert_eq!(test_channel.send(43), Err(43));
+        assert_eq!(test_channel.recv(), Err(chennel_only_channel::Closed));
+    }
+    #[test]
+    fn channel_only_channel_buffer_lazy_and_shrunk() {
+        let test_channel = chennel_only_channel::Channel::<u32>::with_capacity(1024);
+        assert_eq!(test_channel.buffer_capacity(), 0);
+        (0..100).for_each(|msg| assert!(test_channel.send(msg).is_ok()));
+        (0..100).for_each(|_| assert!(test_channel.recv().is_ok()));
+        let _ = test_channel.send(100);
+        // the first shrink keeps room for the burst just gone, the second, with no burst in between,
+        // lets go of it
+        test_channel.shrink_to_fit();
+        assert!(test_channel.buffer_capacity() >= 100);
+        test_channel.shrink_to_fit();
+        assert!(test_channel.buffer_capacity() < 100);
+        assert_eq!(test_channel.recv(), Ok(100));
+    }
+
+    #[test]
+    fn channel_only_channel_scoped_handles() {
+        let test_channel = chennel_only_channel::Channel::<u32>::new();
+        let (test_tx, test_rx) = test_channel.split();
+        let sum: u32 = thread::scope(|scope| {
+            for producer in 0..2 {
+                scope.spawn(move || {
+                    for msg in 0..5 {
+                        assert!(test_tx.send(producer * 5 + msg).is_ok());
+                    }
+                });
+            }
+            (0..10).map(|_| test_rx.recv().unwrap()).sum()
+        });
+        assert_eq!(sum, (0..10).sum::<u32>());
+    }
+
+    // the same scenario against every implementation of the common channel interface
+    fn producer_consumer_in_order<Tx, Rx>(test_tx: &Tx, test_rx: &Rx)
+    where
+        Tx: ChannelSender<u32> + Sync,
+        Rx: ChannelReceiver<u32> + Sync,
+    {
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                for msg in 0..100 {
+                    assert!(test_tx.send(msg).is_ok());
+                }
+            });
+            for expected in 0..100 {
+                assert_eq!(test_rx.recv(), Some(expected));
+            }
+        });
+    }
+
+    #[test]
+    fn every_channel_impl_producer_consumer() {
+        let test_channel = chennel_only_channel::Channel::new();
+        producer_consumer_in_order(&test_channel, &test_channel);
+
+        let test_channel = chennel_only_channel::Channel::with_capacity(2);
+        producer_consumer_in_order(&test_channel, &test_channel);
+
+        let (test_tx, test_rx) = test_channel.split();
+        producer_consumer_in_order(&test_tx, &test_rx);
+
+        let (test_tx, test_rx) = tx_rx_channel::channel();
+        producer_consumer_in_order(&test_tx, &test_rx);
+
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel(0);
+        producer_consumer_in_order(&test_tx, &test_rx);
+
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new().capacity(1).spin(10).build();
+        producer_consumer_in_order(&test_tx, &test_rx);
+    }
+
+    #[test]
+    fn tx_rx_channel_naive_send_recv() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let _ = test_tx.send(42);
+        assert_eq!(test_rx.tx_unaware_recv(), 42);
+        // would hang forever due to the buggy implementation of recv s.t.
+        // wherein there is no mechanism to notify the thread executing the recv call that is blocked
+        // on waiting for the cond var, in the circumstance of no msg and no senders left, that the
+        // blocked call should just return
+        // test_rx.tx_unaware_recv();   
+    }
+
+    /// impl test cases to ensure correct erroneous situations are handled when making send or recv calls
+    #[test]
+    fn rx_err_for_no_tx_before_blocking() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let _ = test_tx.send(42);
+        assert_eq!(test_rx.recv().unwrap(), 42);
+        // a case showing the awareness of the recv call s.t. if all senders are gone and no msg is to receive
+        // the call would return err immediately
+        drop(test_tx);
+        assert!(test_rx.recv().is_err())
+    }
+
+    #[test]
+    fn rx_err_for_no_tx_while_blocking() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+
+        thread::scope(|scope|
+            {   
+                // spawn a background thread that would take and drop the sender after some delay,
+                // while the main thread is blocked in the recv call execution that is waiting on
+                // more msgs to receive
+                scope.spawn(move || {
+                    thread::sleep(Duration::from_secs(3));
+                    drop(test_tx);
+                });
+            });
+        
+        // on the main thread, the recv call initially blocks on waiting for more msgs to receive
+        // but eventually return properly when there is no possibilty to do so (no msg & no sender)
+        assert!(test_rx.recv().is_err());
+    }
+
+    #[test]
+    fn bounded_tx_blocks_while_full() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(2);
+
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                // the first two sends fill the queue up to capacity, and the third send would
+                // block until the receiver on the main thread takes a msg out
+                for msg in 0..3 {
+                    let _ = test_tx.send(msg);
+                }
+            });
+
+            thread::sleep(Duration::from_millis(100));
+            assert_eq!(test_rx.recv().unwrap(), 0);
+            assert_eq!(test_rx.recv().unwrap(), 1);
+            assert_eq!(test_rx.recv().unwrap(), 2);
+        });
+        assert!(test_rx.recv().is_err());
+    }
+
+    #[test]
+    fn try_recv_empty_vs_disconnected() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        assert_eq!(test_rx.try_recv().unwrap_err(), tx_rx_channel::TryRecvError::Empty);
+        let _ = test_tx.send(42);
+        drop(test_tx);
+        // msgs still queued up are delivered before the disconnection is reported
+        assert_eq!(test_rx.try_recv().unwrap(), 42);
+        assert_eq!(test_rx.try_recv().unwrap_err(), tx_rx_channel::TryRecvError::Disconnected);
+    }
+
+    #[test]
+    fn try_send_full_bounded() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(1);
+        assert!(test_tx.try_send(42).is_ok());
+        assert_eq!(test_tx.try_send(43).unwrap_err(), tx_rx_channel::TrySendError::Full(43));
+        assert_eq!(test_rx.recv().unwrap(), 42);
+        assert!(test_tx.try_send(43).is_ok());
+    }
+
+    #[test]
+    fn recv_timeout_vs_disconnected() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        assert_eq!(
+            test_rx.recv_timeout(Duration::from_millis(50)).unwrap_err(),
+            tx_rx_channel::RecvTimeoutError::Timeout
+        );
+
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                let _ = test_tx.send(42);
+            });
+            assert_eq!(test_rx.recv_timeout(Duration::from_secs(5)).unwrap(), 42);
+        });
+        assert_eq!(
+            test_rx.recv_timeout(Duration::from_secs(5)).unwrap_err(),
+            tx_rx_channel::RecvTimeoutError::Disconnected
+        );
+    }
+
+    #[test]
+    fn recv_timeout_past_any_instant_waits_untimed() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                let _ = test_tx.send(42);
+            });
+            assert_eq!(test_rx.recv_timeout(Duration::MAX).unwrap(), 42);
+        });
+        assert_eq!(test_rx.recv_timeout(Duration::MAX).unwrap_err(), tx_rx_channel::RecvTimeoutError::Disconnected);
+    }
+
+    #[test]
+    fn send_timeout_past_any_instant_waits_untimed() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(1);
+        assert!(test_tx.send_timeout(42, Duration::MAX).is_ok());
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(50));
+                assert_eq!(test_rx.recv().unwrap(), 42);
+            });
+            assert!(test_tx.send_timeout(43, Duration::MAX).is_ok());
+        });
+        assert_eq!(test_rx.recv().unwrap(), 43);
+    }
+
+    #[test]
+    fn send_timeout_while_full() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(1);
+        assert!(test_tx.send_timeout(42, Duration::from_millis(50)).is_ok());
+        assert_eq!(
+            test_tx.send_timeout(43, Duration::from_millis(50)).unwrap_err(),
+            tx_rx_channel::SendTimeoutError::Timeout(43)
+        );
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(50));
+                assert_eq!(test_rx.recv().unwrap(), 42);
+            });
+            assert!(test_tx.send_timeout(43, Duration::from_secs(5)).is_ok());
+        });
+        assert_eq!(test_rx.recv().unwrap(), 43);
+    }
+
+    #[test]
+    fn recv_deadline_shared_across_receivers() {
+        let (_test_tx_a, test_rx_a) = tx_rx_channel::channel::<u32>();
+        let (_test_tx_b, test_rx_b) = tx_rx_channel::channel::<u32>();
+        let deadline = Instant::now() + Duration::from_millis(50);
+        assert_eq!(test_rx_a.recv_deadline(deadline).unwrap_err(), tx_rx_channel::RecvTimeoutError::Timeout);
+        // the deadline has passed while waiting on the first receiver, so the second one times out
+        // right away instead of waiting another full duration
+        assert_eq!(test_rx_b.recv_deadline(deadline).unwrap_err(), tx_rx_channel::RecvTimeoutError::Timeout);
+    }
+
+    #[test]
+    fn rx_into_iter_in_worker_thread() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let worker = thread::spawn(move || {
+            let mut received = Vec::new();
+            for msg in test_rx {
+                received.push(msg);
+            }
+            received
+        });
+        for msg in 0..5 {
+            let _ = test_tx.send(msg);
+        }
+        drop(test_tx);
+        assert_eq!(worker.join().unwrap(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn rx_try_iter_drains_without_blocking() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        assert_eq!(test_rx.try_iter().count(), 0);
+        for msg in 0..3 {
+            let _ = test_tx.send(msg);
+        }
+        // the sender is still alive, yet the iteration ends once the current backlog is drained
+        assert_eq!(test_rx.try_iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+        let _ = test_tx.send(3);
+        assert_eq!(test_rx.try_iter().collect::<Vec<_>>(), vec![3]);
+    }
+
+    #[test]
+    fn mpmc_worker_pool_each_msg_once() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let worker_rx = test_rx.clone();
+                thread::spawn(move || worker_rx.into_iter().collect::<Vec<_>>())
+            })
+            .collect();
+        drop(test_rx);
+
+        for msg in 0..100 {
+            let _ = test_tx.send(msg);
+        }
+        // every worker blocked in recv is woken up on the disconnection and ends its loop
+        drop(test_tx);
+
+        let mut received: Vec<u32> = workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect();
+        received.sort();
+        assert_eq!(received, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn tx_err_only_after_last_rx_dropped() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let test_rx_clone = test_rx.clone();
+        drop(test_rx);
+        assert!(test_tx.send(42).is_ok());
+        assert_eq!(test_rx_clone.recv().unwrap(), 42);
+        drop(test_rx_clone);
+        assert_eq!(test_tx.send(43).unwrap_err().0, 43);
+    }
+
+    // bare-bones executor to drive the futures of the channel to completion in the tests, parking
+    // the test thread until the waker handed to the future is woken
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake};
+
+        struct ThreadWaker(thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let mut future = std::pin::pin!(future);
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn rx_recv_async() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            let _ = test_tx.send(42);
+            thread::sleep(Duration::from_millis(50));
+        });
+        assert_eq!(block_on(test_rx.recv_async()).unwrap(), 42);
+        // the future also resolves on the disconnection, once the sender is dropped
+        assert!(block_on(test_rx.recv_async()).is_err());
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn futures_sink_into_stream() {
+        use futures::{executor::block_on, SinkExt, StreamExt};
+
+        let (mut test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(2);
+        let producer = thread::spawn(move || {
+            block_on(async {
+                for msg in 0..10 {
+                    // waits on the readiness of the sink whenever the bounded channel is full, the
+                    // inherent blocking send being shadowed by SinkExt::send
+                    assert!(SinkExt::send(&mut test_tx, msg).await.is_ok());
+                }
+            })
+        });
+        let received: Vec<u32> = block_on(StreamExt::map(test_rx, |msg| msg * 2).collect());
+        assert_eq!(received, (0..10).map(|msg| msg * 2).collect::<Vec<_>>());
+        producer.join().unwrap();
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn rx_into_tokio_stream() {
+        use tokio_stream::StreamExt;
+
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(0);
+        let producer = thread::spawn(move || {
+            for msg in 0..10 {
+                let _ = test_tx.send(msg);
+            }
+        });
+        // any executor can drive the stream, the tokio channel only needing a waker
+        let received: Vec<u32> = block_on(test_rx.into_tokio_stream().collect());
+        assert_eq!(received, (0..10).collect::<Vec<_>>());
+        producer.join().unwrap();
+
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        drop(test_rx.into_tokio_stream());
+        // the forwarding thread gives up on the first msg after the stream is gone
+        let _ = test_tx.send(1);
+        while test_tx.send(2).is_ok() {
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
+
+    #[test]
+    fn rendezvous_tx_blocks_until_taken() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(0);
+        let taken = std::sync::atomic::AtomicBool::new(false);
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(50));
+                taken.store(true, std::sync::atomic::Ordering::SeqCst);
+                assert_eq!(test_rx.recv().unwrap(), 42);
+            });
+            let _ = test_tx.send(42);
+            // the send can only have returned after the receiver took the msg
+            assert!(taken.load(std::sync::atomic::Ordering::SeqCst));
+        });
+
+        // with no receiver waiting, there's no one to hand the msg to
+        assert_eq!(test_tx.try_send(43).unwrap_err(), tx_rx_channel::TrySendError::Full(43));
+        assert_eq!(
+            test_tx.send_timeout(43, Duration::from_millis(50)).unwrap_err(),
+            tx_rx_channel::SendTimeoutError::Timeout(43)
+        );
+        assert!(test_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn spinning_rendezvous_tx_done_once_its_own_msg_taken() {
+        // spinning for all the rounds there are, s.t. the senders are still spinning when the msgs
+        // are taken
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new().capacity(0).spin(u32::MAX).build::<u32>();
+        let (done_tx, done_rx) = std::sync::mpsc::channel();
+        // the receiver is moved in, to be dropped for the senders to give up, should the test fail
+        thread::scope(move |scope| {
+            for msg in [1, 2] {
+                let (test_tx, done_tx) = (test_tx.clone(), done_tx.clone());
+                scope.spawn(move || {
+                    let _ = test_tx.send(msg);
+                    let _ = done_tx.send(msg);
+                });
+            }
+            thread::sleep(Duration::from_millis(50));
+            let first = test_rx.recv().unwrap();
+            // the other sender has its msg in the slot by now, which nobody takes until the sender
+            // of the first msg is done
+            assert_eq!(done_rx.recv_timeout(Duration::from_secs(5)), Ok(first));
+            let second = test_rx.recv().unwrap();
+            assert_eq!(done_rx.recv_timeout(Duration::from_secs(5)), Ok(second));
+        });
+
+        // the spin gives up along with the timeout, however many rounds it has left
+        let (test_tx, _test_rx) = tx_rx_channel::ChannelBuilder::new().capacity(1).spin(u32::MAX).build::<u32>();
+        let _ = test_tx.send(42);
+        assert_eq!(
+            test_tx.send_timeout(43, Duration::from_millis(50)).unwrap_err(),
+            tx_rx_channel::SendTimeoutError::Timeout(43)
+        );
+    }
+
+    #[test]
+    fn rendezvous_try_send_hands_over_to_waiting_rx() {
+        use std::future::Future;
+        use std::task::{Context, Waker};
+
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(0);
+        thread::scope(|scope| {
+            let receiver = scope.spawn(|| test_rx.recv().unwrap());
+            thread::sleep(Duration::from_millis(50));
+            assert!(test_tx.try_send(42).is_ok());
+            // the try_send returned once the msg was taken, not once it was in the queue
+            assert!(test_tx.is_empty());
+            assert_eq!(receiver.join().unwrap(), 42);
+        });
+        // a task parked on recv_async is a receiver waiting all the same
+        thread::scope(|scope| {
+            let receiver = scope.spawn(|| block_on(test_rx.recv_async()).unwrap());
+            thread::sleep(Duration::from_millis(50));
+            assert!(test_tx.try_send(43).is_ok());
+            assert_eq!(receiver.join().unwrap(), 43);
+        });
+
+        // the msg is handed back once the task it was handed over to goes away without it
+        let mut recv_future = Box::pin(test_rx.recv_async());
+        assert!(recv_future.as_mut().poll(&mut Context::from_waker(Waker::noop())).is_pending());
+        thread::scope(|scope| {
+            let sender = scope.spawn(|| test_tx.try_send(44));
+            thread::sleep(Duration::from_millis(50));
+            drop(recv_future);
+            assert_eq!(sender.join().unwrap(), Err(tx_rx_channel::TrySendError::Full(44)));
+        });
+        assert!(test_tx.is_empty());
+        assert_eq!(test_tx.stats().received, 2);
+    }
+
+    #[test]
+    fn len_and_capacity_introspection() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(4);
+        assert_eq!(test_tx.capacity(), Some(4));
+        assert!(test_rx.is_empty());
+        let _ = test_tx.send(42);
+        let _ = test_tx.send(43);
+        assert_eq!(test_tx.len(), 2);
+        assert_eq!(test_rx.len(), 2);
+        let _ = test_rx.recv();
+        assert_eq!(test_rx.len(), 1);
+
+        let (test_tx, _test_rx) = tx_rx_channel::channel::<u32>();
+        assert_eq!(test_tx.capacity(), None);
+    }
+
+    #[test]
+    fn rx_drain_whole_backlog() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(3);
+        for msg in 0..3 {
+            let _ = test_tx.send(msg);
+        }
+        assert_eq!(test_rx.drain(), vec![0, 1, 2]);
+        assert!(test_rx.drain().is_empty());
+        // all the slots are free again
+        for msg in 3..6 {
+            assert!(test_tx.try_send(msg).is_ok());
+        }
+    }
+
+    #[test]
+    fn close_lets_rx_drain_then_disconnects() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let _ = test_tx.send(42);
+        test_tx.close();
+        assert_eq!(test_tx.send(43).unwrap_err().0, 43);
+        // the handles are still alive, yet the msg queued up before the close is the last one
+        assert_eq!(test_rx.recv().unwrap(), 42);
+        assert!(test_rx.recv().is_err());
+    }
+
+    #[test]
+    fn rx_close_wakes_blocked_rx() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(50));
+                test_rx.close();
+            });
+            assert!(test_rx.recv().is_err());
+        });
+    }
+
+    #[test]
+    fn weak_tx_doesnt_keep_channel_alive() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let weak_tx = test_tx.downgrade();
+
+        let upgraded_tx = weak_tx.upgrade().unwrap();
+        let _ = upgraded_tx.send(42);
+        drop(upgraded_tx);
+        assert_eq!(test_rx.recv().unwrap(), 42);
+
+        drop(test_tx);
+        // the weak sender is still around, yet the receiver finds out about the disconnection...
+        assert!(test_rx.recv().is_err());
+        // ...which can't be undone by upgrading the weak sender
+        assert!(weak_tx.upgrade().is_none());
     }
-}
 
+    #[test]
+    fn batched_send_all_recv_many() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(4);
+        let producer = thread::spawn(move || {
+            // more msgs than the capacity, s.t. the batch goes in over several rounds
+            assert!(test_tx.send_all(0..10).is_ok());
+        });
 
+        let mut received = Vec::new();
+        while let Ok(taken_cnt) = test_rx.recv_many(&mut received, 3) {
+            assert!((1..=3).contains(&taken_cnt));
+        }
+        producer.join().unwrap();
+        assert_eq!(received, (0..10).collect::<Vec<_>>());
+    }
 
-#[cfg(test)]
-mod tests{
-    use std::{thread, time::Duration};
+    #[test]
+    fn send_all_hands_back_unsent() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        drop(test_rx);
+        assert_eq!(test_tx.send_all(vec![1, 2, 3]).unwrap_err().0, vec![1, 2, 3]);
+    }
 
-    use super::*;
     #[test]
-    fn channel_only_channel_basic_send_recv() {
-        todo!()
+    fn send_batch_wakeup_relayed_to_every_blocked_rx() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let mut received: Vec<u32> = thread::scope(|scope| {
+            let mut consumers: Vec<_> = (0..2)
+                .map(|_| {
+                    let test_rx = test_rx.clone();
+                    scope.spawn(move || {
+                        let start = Instant::now();
+                        let msg = test_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+                        // a wakeup not passed on would leave a consumer blocked until the timeout
+                        assert!(start.elapsed() < Duration::from_secs(5));
+                        msg
+                    })
+                })
+                .collect();
+            // the wakeup goes on to a parked task as well
+            let async_rx = test_rx.clone();
+            consumers.push(scope.spawn(move || block_on(async_rx.recv_async()).unwrap()));
+            thread::sleep(Duration::from_millis(50));
+            assert!(test_tx.send_batch(vec![0, 1, 2]).is_ok());
+            consumers.into_iter().map(|consumer| consumer.join().unwrap()).collect()
+        });
+        received.sort();
+        assert_eq!(received, vec![0, 1, 2]);
+        drop(test_rx);
+        assert_eq!(test_tx.send_batch(vec![3, 4]).unwrap_err().0, vec![3, 4]);
+    }
+
+    #[test]
+    fn send_all_wakes_every_blocked_rx() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let mut received: Vec<u32> = thread::scope(|scope| {
+            let consumers: Vec<_> = (0..3)
+                .map(|_| {
+                    let test_rx = test_rx.clone();
+                    scope.spawn(move || {
+                        let start = Instant::now();
+                        let msg = test_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+                        // a consumer not woken up by the batch would be left blocked until the timeout
+                        assert!(start.elapsed() < Duration::from_secs(5));
+                        msg
+                    })
+                })
+                .collect();
+            thread::sleep(Duration::from_millis(50));
+            assert!(test_tx.send_all(vec![0, 1, 2]).is_ok());
+            consumers.into_iter().map(|consumer| consumer.join().unwrap()).collect()
+        });
+        received.sort();
+        assert_eq!(received, vec![0, 1, 2]);
     }
+
     #[test]
-    fn tx_rx_channel_naive_send_recv() {
+    fn wait_capacity_until_enough_room() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(3);
+        assert!(test_tx.send_all(0..3).is_ok());
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                for _ in 0..2 {
+                    thread::sleep(Duration::from_millis(50));
+                    let _ = test_rx.recv();
+                }
+            });
+            let start = Instant::now();
+            // one slot freed is not enough, but the second is
+            assert_eq!(test_tx.wait_capacity(2), Ok(()));
+            assert!(start.elapsed() >= Duration::from_millis(100));
+            assert_eq!(test_tx.len(), 1);
+        });
+        drop(test_rx);
+        assert_eq!(test_tx.wait_capacity(3), Err(tx_rx_channel::WaitCapacityError::Disconnected));
+    }
+
+    #[test]
+    fn paused_channel_holds_off_tx_until_resumed() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let _ = test_tx.send(41);
+        test_rx.pause();
+        assert_eq!(test_tx.try_send(42).unwrap_err(), tx_rx_channel::TrySendError::Full(42));
+        // the msg queued up before the pause is still there to receive
+        assert_eq!(test_rx.recv(), Ok(41));
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(50));
+                assert!(test_tx.is_empty());
+                test_rx.resume();
+            });
+            assert!(test_tx.send(42).is_ok());
+        });
+        assert_eq!(test_rx.recv(), Ok(42));
+
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new()
+            .capacity(4)
+            .overflow_policy(tx_rx_channel::OverflowPolicy::Fail)
+            .build::<u32>();
+        test_rx.pause();
+        assert!(test_rx.is_paused());
+        assert_eq!(test_tx.try_send(43).unwrap_err(), tx_rx_channel::TrySendError::Full(43));
+        assert_eq!(test_tx.send_timeout(43, Duration::from_secs(5)).unwrap_err(), tx_rx_channel::SendTimeoutError::Full(43));
+    }
+
+    #[test]
+    fn sender_and_receiver_counts() {
         let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let other_tx = test_tx.clone();
+        let weak_tx = test_tx.downgrade();
+        let other_rx = test_rx.clone();
+        assert_eq!((test_rx.sender_count(), test_rx.receiver_count()), (2, 2));
+        drop(other_tx);
+        drop(other_rx);
+        assert_eq!((test_tx.sender_count(), test_tx.receiver_count()), (1, 1));
+        drop(test_rx);
+        assert_eq!(test_tx.receiver_count(), 0);
+        drop(weak_tx);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn tracing_events_and_wait_spans_named_after_channel() {
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        // the bare minimum of a subscriber, recording the names of the spans and the msgs of the
+        // events, each along with the channel field
+        #[derive(Clone, Default)]
+        struct RecordingSubscriber(Arc<Mutex<Vec<(String, String)>>>);
+
+        #[derive(Default)]
+        struct ChannelVisitor {
+            channel: String,
+            message: String,
+        }
+
+        impl Visit for ChannelVisitor {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                if field.name() == "channel" {
+                    self.channel = value.to_string();
+                }
+            }
+
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "message" {
+                    self.message = format!("{value:?}");
+                }
+            }
+        }
+
+        impl Subscriber for RecordingSubscriber {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut visitor = ChannelVisitor::default();
+                span.record(&mut visitor);
+                self.0.lock().unwrap().push((visitor.channel, span.metadata().name().to_string()));
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut visitor = ChannelVisitor::default();
+                event.record(&mut visitor);
+                self.0.lock().unwrap().push((visitor.channel, visitor.message));
+            }
+
+            fn enter(&self, _span: &Id) {}
+
+            fn exit(&self, _span: &Id) {}
+        }
+
+        let subscriber = RecordingSubscriber::default();
+        let recorded = Arc::clone(&subscriber.0);
+        tracing::subscriber::with_default(subscriber, || {
+            let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new().name("events").build::<u32>();
+            let _ = test_tx.send(42);
+            let _ = test_rx.recv();
+            drop(test_tx);
+        });
+        let recorded: Vec<(String, String)> = recorded.lock().unwrap().clone();
+        let expected = ["msgs sent", "msgs taken", "all senders gone", "all receivers gone"];
+        assert_eq!(recorded, expected.map(|what| ("events".to_string(), what.to_string())));
+
+        // a wait happens with the lock released, so on the thread of the receiver rather than
+        // the one of the sender, which is where the span is to be found
+        let subscriber = RecordingSubscriber::default();
+        let recorded = Arc::clone(&subscriber.0);
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new().name("events").build::<u32>();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            drop(test_tx);
+        });
+        tracing::subscriber::with_default(subscriber, || assert!(test_rx.recv().is_err()));
+        assert!(recorded.lock().unwrap().contains(&("events".to_string(), "recv_wait".to_string())));
+    }
+
+    #[test]
+    fn send_with_builds_only_msgs_that_go_in() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<Vec<u8>>(1);
+        let built_cnt = std::sync::atomic::AtomicUsize::new(0);
+        let make_value = || {
+            built_cnt.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            vec![0; 1024]
+        };
+        assert!(test_tx.send_with(make_value).is_ok());
+        thread::scope(|scope| {
+            let producer = scope.spawn(|| test_tx.send_with(make_value));
+            thread::sleep(Duration::from_millis(50));
+            // not built while the sender waits for room
+            assert_eq!(built_cnt.load(std::sync::atomic::Ordering::Relaxed), 1);
+            assert_eq!(test_rx.recv().map(|msg| msg.len()), Ok(1024));
+            assert!(producer.join().unwrap().is_ok());
+        });
+        assert_eq!(built_cnt.load(std::sync::atomic::Ordering::Relaxed), 2);
+
+        assert_eq!(test_rx.recv().map(|msg| msg.len()), Ok(1024));
+        assert!(test_tx.send_boxed(Box::new(vec![2])).is_ok());
+        drop(test_rx);
+        assert_eq!(test_tx.send_with(make_value), Err(tx_rx_channel::NoMoreReceiverErr(None)));
+        assert_eq!(test_tx.send_boxed(Box::new(vec![1])), Err(tx_rx_channel::NoMoreReceiverErr(Box::new(vec![1]))));
+        assert_eq!(built_cnt.load(std::sync::atomic::Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn observer_told_of_every_msg_in_and_out() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Default)]
+        struct RecordingObserver {
+            events: Mutex<Vec<(&'static str, u64)>>,
+            latencies: Mutex<Vec<Duration>>,
+        }
+
+        impl tx_rx_channel::ChannelObserver for RecordingObserver {
+            fn on_send(&self, seq: u64) {
+                self.events.lock().unwrap().push(("send", seq));
+            }
+
+            fn on_recv(&self, seq: u64, queued_for: Duration) {
+                self.events.lock().unwrap().push(("recv", seq));
+                self.latencies.lock().unwrap().push(queued_for);
+            }
+        }
+
+        let observer = Arc::new(RecordingObserver::default());
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new().observer(Arc::clone(&observer)).build::<u32>();
+        let _ = test_tx.send(41);
+        thread::sleep(Duration::from_millis(50));
+        let _ = test_rx.recv();
+        assert!(test_tx.send_all(vec![42, 43]).is_ok());
+        let _ = test_rx.drain();
+        assert_eq!(
+            *observer.events.lock().unwrap(),
+            vec![("send", 0), ("recv", 0), ("send", 1), ("send", 2), ("recv", 1), ("recv", 2)]
+        );
+        assert!(observer.latencies.lock().unwrap()[0] >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn unacked_delivery_requeued() {
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new().sequenced().build::<u32>();
+        let _ = test_tx.send_all([41, 42]);
+        let consumer_panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let delivery = test_rx.recv_delivery().unwrap();
+            assert_eq!(*delivery, 41);
+            panic!("consumer failing on {}", *delivery);
+        }));
+        assert!(consumer_panic.is_err());
+        // the msg not acked goes back in front of the msgs already waiting, as it was
+        assert_eq!(test_rx.recv_with_seq().unwrap(), (0, 41));
+        assert_eq!(test_rx.recv_delivery().unwrap().ack(), 42);
+        drop(test_tx);
+        assert!(test_rx.recv_delivery().is_err());
+        let stats = test_rx.stats();
+        assert_eq!((stats.sent, stats.received), (2, 2));
+    }
+
+    #[test]
+    fn unacked_delivery_keeps_its_slot() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(1);
+        test_tx.send(41).unwrap();
+        let delivery = test_rx.recv_delivery().unwrap();
+        assert_eq!(test_tx.try_send(42).unwrap_err(), tx_rx_channel::TrySendError::Full(42));
+        drop(delivery);
+        assert_eq!(test_tx.try_send(42).unwrap_err(), tx_rx_channel::TrySendError::Full(42));
+        let delivery = test_rx.recv_delivery().unwrap();
+        assert_eq!(delivery.ack(), 41);
+        // the slot handed over to the senders with the ack
+        assert!(test_tx.try_send(42).is_ok());
+        assert_eq!(test_rx.recv().unwrap(), 42);
+    }
+
+    #[test]
+    fn rendezvous_sender_waits_out_unacked_delivery() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(0);
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                assert!(test_tx.send(41).is_ok());
+                // no room for this one to be handed over while 41 is out, and then put back
+                assert_eq!(
+                    test_tx.send_timeout(42, Duration::from_millis(50)).unwrap_err(),
+                    tx_rx_channel::SendTimeoutError::Timeout(42)
+                );
+            });
+            let delivery = test_rx.recv_delivery().unwrap();
+            thread::sleep(Duration::from_millis(100));
+            drop(delivery);
+        });
+        // the timed out sender took back its own msg, not the one put back
+        assert_eq!(test_rx.try_recv(), Ok(41));
+        assert_eq!(test_rx.try_recv().unwrap_err(), tx_rx_channel::TryRecvError::Empty);
+    }
+
+    #[test]
+    fn channel_stats_counters() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(2);
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                for msg in 0..3 {
+                    let _ = test_tx.send(msg);
+                }
+            });
+            thread::sleep(Duration::from_millis(50));
+            for _ in 0..3 {
+                let _ = test_rx.recv();
+            }
+        });
+
+        let stats = test_rx.stats();
+        assert_eq!(stats, test_tx.stats());
+        assert_eq!((stats.sent, stats.received, stats.peak_depth), (3, 3, 2));
+        // the third send finds the channel full
+        assert!(stats.blocking_waits >= 1);
+    }
+
+    #[test]
+    fn overflow_policies_when_full() {
+        use tx_rx_channel::{ChannelBuilder, OverflowPolicy, SendTimeoutError, TrySendError};
+
+        let (test_tx, test_rx) = ChannelBuilder::new().capacity(2).overflow_policy(OverflowPolicy::DropOldest).build::<u32>();
+        assert!(test_tx.send_all(0..5).is_ok());
+        assert_eq!(test_rx.drain(), vec![3, 4]);
+        assert_eq!(test_rx.stats().dropped, 3);
+
+        let (test_tx, test_rx) = ChannelBuilder::new().capacity(2).overflow_policy(OverflowPolicy::DropNewest).build::<u32>();
+        for msg in 0..5 {
+            assert!(test_tx.send(msg).is_ok());
+        }
+        assert_eq!(test_rx.drain(), vec![0, 1]);
+        assert_eq!(test_rx.stats().dropped, 3);
+
+        let (test_tx, test_rx) = ChannelBuilder::new().capacity(2).overflow_policy(OverflowPolicy::Fail).build::<u32>();
+        assert!(test_tx.send_all(0..2).is_ok());
+        assert_eq!(test_tx.send_timeout(2, Duration::from_secs(5)), Err(SendTimeoutError::Full(2)));
+        assert_eq!(test_tx.try_send(2), Err(TrySendError::Full(2)));
+        thread::scope(|scope| {
+            // the blocking send can't fail for a full channel, and waits for room instead
+            let producer = scope.spawn(|| test_tx.send(2));
+            thread::sleep(Duration::from_millis(50));
+            assert!(!producer.is_finished());
+            assert_eq!(test_rx.recv(), Ok(0));
+            assert!(producer.join().unwrap().is_ok());
+        });
+        assert_eq!(test_rx.drain(), vec![1, 2]);
+        assert_eq!(test_rx.stats().dropped, 0);
+    }
+
+    #[test]
+    fn expired_msgs_skipped_by_rx() {
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new()
+            .capacity(2)
+            .ttl(Duration::from_millis(20))
+            .build::<u32>();
+        let _ = test_tx.send(41);
         let _ = test_tx.send(42);
-        assert_eq!(test_rx.tx_unaware_recv(), 42);
-        // would hang forever due to the buggy implementation of recv s.t.
-        // wherein there is no mechanism to notify the thread executing the recv call that is blocked
-        // on waiting for the cond var, in the circumstance of no msg and no senders left, that the
-        // blocked call should just return
-        // test_rx.tx_unaware_recv();   
+        thread::sleep(Duration::from_millis(50));
+        thread::scope(|scope| {
+            // blocked on the queue full of expired msgs until the receiver skips them
+            scope.spawn(|| test_tx.send(43));
+            assert_eq!(test_rx.recv().unwrap(), 43);
+        });
+        assert_eq!(test_rx.try_recv().unwrap_err(), tx_rx_channel::TryRecvError::Empty);
+
+        let stats = test_rx.stats();
+        assert_eq!((stats.sent, stats.received, stats.expired), (3, 1, 2));
     }
 
-    /// impl test cases to ensure correct erroneous situations are handled when making send or recv calls
     #[test]
-    fn rx_err_for_no_tx_before_blocking() {
+    fn ttl_past_any_instant_never_expires() {
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new().ttl(Duration::MAX).build::<u32>();
+        let _ = test_tx.send(42);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(test_rx.try_recv(), Ok(42));
+        assert_eq!(test_rx.stats().expired, 0);
+    }
+
+    #[test]
+    fn mock_clock_drives_ttl_and_timeouts() {
+        let clock = tx_rx_channel::MockClock::new();
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new()
+            .capacity(1)
+            .ttl(Duration::from_secs(60))
+            .clock(clock.clone())
+            .build::<u32>();
+        let _ = test_tx.send(41);
+        clock.advance(Duration::from_secs(59));
+        assert_eq!(test_rx.stats().expired, 0);
+        // a full minute passes without anyone sleeping through it
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(test_rx.try_recv(), Err(tx_rx_channel::TryRecvError::Empty));
+        assert_eq!(test_rx.stats().expired, 1);
+
+        // the waits for an hour on the full channel, and then on the empty one, end with the hour
+        // the clock is moved on by, which wakes the waiting thread up, rather than the thread looking
+        // at the clock over and over in the meantime
+        let _ = test_tx.send(42);
+        let blocking_waits = test_rx.stats().blocking_waits;
+        thread::scope(|scope| {
+            let sender = scope.spawn(|| test_tx.send_timeout(43, Duration::from_secs(3600)));
+            thread::sleep(Duration::from_millis(50));
+            clock.advance(Duration::from_secs(3600));
+            assert_eq!(sender.join().unwrap(), Err(tx_rx_channel::SendTimeoutError::Timeout(43)));
+        });
+        // the hour outlasted the ttl of the msg in the way as well
+        assert_eq!(test_rx.try_recv(), Err(tx_rx_channel::TryRecvError::Empty));
+        assert_eq!(test_rx.stats().expired, 2);
+        thread::scope(|scope| {
+            let receiver = scope.spawn(|| test_rx.recv_timeout(Duration::from_secs(3600)));
+            thread::sleep(Duration::from_millis(50));
+            clock.advance(Duration::from_secs(3600));
+            assert_eq!(receiver.join().unwrap(), Err(tx_rx_channel::RecvTimeoutError::Timeout));
+        });
+        assert!(test_rx.stats().blocking_waits <= blocking_waits + 2);
+    }
+
+    #[test]
+    fn builder_config_applies_to_channel() {
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new().name("events").capacity(3).build::<u32>();
+        assert_eq!(test_tx.name(), Some("events"));
+        assert_eq!(test_rx.name(), Some("events"));
+        assert_eq!(test_rx.capacity(), Some(3));
+
+        let (test_tx, _test_rx) = tx_rx_channel::ChannelBuilder::new().build::<u32>();
+        assert_eq!((test_tx.name(), test_tx.capacity()), (None, None));
+
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new().segment_size(2).build::<u32>();
+        assert!(test_tx.send_all(0..7).is_ok());
+        assert_eq!(test_rx.recv().unwrap(), 0);
+        assert_eq!(test_rx.drain(), (1..7).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "limit of 0")]
+    fn recv_many_rejects_zero_limit() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let _ = test_tx.send(42);
+        let _ = test_rx.recv_many(&mut Vec::new(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn builder_rejects_rendezvous_without_blocking() {
+        let _ = tx_rx_channel::ChannelBuilder::new()
+            .capacity(0)
+            .overflow_policy(tx_rx_channel::OverflowPolicy::Fail)
+            .build::<u32>();
+    }
+
+    #[test]
+    #[should_panic(expected = "can't have a ttl")]
+    fn builder_rejects_rendezvous_with_ttl() {
+        let _ = tx_rx_channel::ChannelBuilder::new()
+            .capacity(0)
+            .ttl(Duration::from_secs(1))
+            .build::<u32>();
+    }
+
+    #[test]
+    fn rx_tee_every_msg_to_each() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(2);
+        let mut test_rxs = test_rx.tee(3);
+        drop(test_rxs.pop());
+        let consumers: Vec<_> = test_rxs
+            .into_iter()
+            .map(|test_rx| thread::spawn(move || test_rx.into_iter().collect::<Vec<_>>()))
+            .collect();
+        assert!(test_tx.send_all(0..10).is_ok());
+        drop(test_tx);
+        for consumer in consumers {
+            assert_eq!(consumer.join().unwrap(), (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn rx_tee_to_none_drops_rx() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        assert!(test_rx.tee(0).is_empty());
+        // no thread left to take the msg and drop it on the floor
+        assert_eq!(test_tx.send(42), Err(tx_rx_channel::NoMoreReceiverErr(42)));
+    }
+
+    #[test]
+    fn rx_combinators_pipeline() {
+        let (test_tx_a, test_rx_a) = tx_rx_channel::channel::<u32>();
+        let (test_tx_b, test_rx_b) = tx_rx_channel::channel::<u32>();
+        let merged_rx = tx_rx_channel::Receiver::merge(test_rx_a, test_rx_b);
+        let pipeline_rx = merged_rx.filter(|msg| msg % 2 == 0).map(|msg| msg * 10);
+        assert!(test_tx_a.send_all(0..5).is_ok());
+        assert!(test_tx_b.send_all(5..10).is_ok());
+        drop((test_tx_a, test_tx_b));
+
+        let mut received: Vec<u32> = std::iter::from_fn(|| pipeline_rx.recv()).collect();
+        received.sort();
+        assert_eq!(received, vec![0, 20, 40, 60, 80]);
+    }
+
+    #[test]
+    fn is_closed_on_both_ends() {
         let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        assert!(!test_tx.is_closed() && !test_rx.is_closed());
         let _ = test_tx.send(42);
+        drop(test_tx);
+        // not before the last msg is taken
+        assert!(!test_rx.is_closed());
         assert_eq!(test_rx.recv().unwrap(), 42);
-        // a case showing the awareness of the recv call s.t. if all senders are gone and no msg is to receive
-        // the call would return err immediately
+        assert!(test_rx.is_closed());
+
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        drop(test_rx);
+        assert!(test_tx.is_closed());
+    }
+
+    #[test]
+    fn same_channel_across_clones() {
+        let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let (other_tx, other_rx) = tx_rx_channel::channel::<u32>();
+        assert!(test_tx.same_channel(&test_tx.clone()));
+        assert!(test_rx.same_channel(&test_rx.clone()));
+        assert!(!test_tx.same_channel(&other_tx));
+        assert!(!test_rx.same_channel(&other_rx));
+    }
+
+    #[test]
+    fn rx_recv_with_seq_across_txs() {
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new()
+            .capacity(2)
+            .overflow_policy(tx_rx_channel::OverflowPolicy::DropOldest)
+            .sequenced()
+            .build::<char>();
+        let other_tx = test_tx.clone();
+        let _ = test_tx.send('a');
+        let _ = other_tx.send('b');
+        assert_eq!(test_rx.recv_with_seq().unwrap(), (0, 'a'));
+        let _ = test_tx.send('c');
+        // evicts 'b' to make room
+        let _ = other_tx.send('d');
+        assert_eq!(test_rx.recv_with_seq().unwrap(), (2, 'c'));
+        assert_eq!(test_rx.recv_with_seq().unwrap(), (3, 'd'));
+    }
+
+    #[test]
+    fn sequenced_txs_received_in_order() {
+        let (test_tx, test_rx) = tx_rx_channel::ChannelBuilder::new().capacity(4).sequenced().build::<(usize, u32)>();
+        let test_txs: Vec<_> = (0..3).map(|_| test_tx.clone()).chain(test_tx.downgrade().upgrade()).collect();
         drop(test_tx);
-        assert!(test_rx.recv().is_err())
+        let mut received = vec![];
+        thread::scope(|scope| {
+            for (tx_idx, test_tx) in test_txs.into_iter().enumerate() {
+                scope.spawn(move || test_tx.send_all((0..50).map(|msg| (tx_idx, msg))));
+            }
+            // every flavor of recv goes through the order checks of a debug build
+            while let Ok(taken_cnt) = test_rx.recv_many(&mut received, 3) {
+                if let Ok((_, msg)) = test_rx.recv_with_seq() {
+                    received.push(msg);
+                }
+                received.extend(test_rx.drain());
+                assert!(taken_cnt > 0);
+            }
+        });
+        for tx_idx in 0..4 {
+            let msgs: Vec<u32> = received.iter().filter(|(idx, _)| *idx == tx_idx).map(|(_, msg)| *msg).collect();
+            assert_eq!(msgs, (0..50).collect::<Vec<_>>());
+        }
     }
 
     #[test]
-    fn rx_err_for_no_tx_while_blocking() {
+    #[should_panic(expected = "isn't sequenced")]
+    fn recv_with_seq_on_unsequenced_channel_panics() {
         let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+        let _ = test_tx.send(42);
+        let _ = test_rx.recv_with_seq();
+    }
 
-        thread::scope(|scope|
-            {   
-                // spawn a background thread that would take and drop the sender after some delay,
-                // while the main thread is blocked in the recv call execution that is waiting on
-                // more msgs to receive
-                scope.spawn(move || {
-                    thread::sleep(Duration::from_secs(3));
-                    drop(test_tx);
-                });
-            });
-        
-        // on the main thread, the recv call initially blocks on waiting for more msgs to receive
-        // but eventually return properly when there is no possibilty to do so (no msg & no sender)
-        assert!(test_rx.recv().is_err());
+    #[test]
+    fn set_capacity_grow_and_shrink() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(1);
+        let _ = test_tx.send(0);
+        thread::scope(|scope| {
+            let producer = scope.spawn(|| test_tx.send(1));
+            thread::sleep(Duration::from_millis(50));
+            assert!(!producer.is_finished());
+            // the blocked send goes through without any recv
+            assert_eq!(test_rx.set_capacity(2), Ok(()));
+            assert!(producer.join().unwrap().is_ok());
+        });
+        assert_eq!(test_tx.capacity(), Some(2));
+
+        // the 2 msgs queued up stay, but there's no room for a 3rd until both are taken
+        assert_eq!(test_tx.set_capacity(1), Ok(()));
+        assert_eq!(test_tx.try_send(2).unwrap_err(), tx_rx_channel::TrySendError::Full(2));
+        assert_eq!(test_rx.recv().unwrap(), 0);
+        assert_eq!(test_tx.try_send(2).unwrap_err(), tx_rx_channel::TrySendError::Full(2));
+        assert_eq!(test_rx.recv().unwrap(), 1);
+        assert!(test_tx.try_send(2).is_ok());
+    }
+
+    #[test]
+    fn set_capacity_rejected_for_fixed_or_zero_capacity() {
+        let (unbounded_tx, _unbounded_rx) = tx_rx_channel::channel::<u32>();
+        let (rendezvous_tx, _rendezvous_rx) = tx_rx_channel::sync_channel::<u32>(0);
+        let (bounded_tx, _bounded_rx) = tx_rx_channel::sync_channel::<u32>(2);
+        assert_eq!(unbounded_tx.set_capacity(2), Err(tx_rx_channel::SetCapacityError::FixedCapacity));
+        assert_eq!(rendezvous_tx.set_capacity(2), Err(tx_rx_channel::SetCapacityError::FixedCapacity));
+        assert_eq!(bounded_tx.set_capacity(0), Err(tx_rx_channel::SetCapacityError::ZeroCapacity));
+        assert_eq!((unbounded_tx.capacity(), rendezvous_tx.capacity(), bounded_tx.capacity()), (None, Some(0), Some(2)));
+    }
+
+    #[test]
+    fn wait_capacity_beyond_capacity_fails() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(3);
+        assert_eq!(test_tx.wait_capacity(4), Err(tx_rx_channel::WaitCapacityError::Unreachable));
+        assert!(test_tx.send_all(0..3).is_ok());
+        thread::scope(|scope| {
+            let waiter = scope.spawn(|| test_tx.wait_capacity(3));
+            thread::sleep(Duration::from_millis(50));
+            // the room waited for is no longer there to be had, however many msgs are taken
+            assert_eq!(test_rx.set_capacity(2), Ok(()));
+            assert_eq!(waiter.join().unwrap(), Err(tx_rx_channel::WaitCapacityError::Unreachable));
+        });
+        drop(test_rx);
+        assert_eq!(test_tx.wait_capacity(4), Err(tx_rx_channel::WaitCapacityError::Disconnected));
+    }
+
+    #[test]
+    fn errors_compose_with_question_mark() {
+        fn round_trip() -> Result<u32, Box<dyn std::error::Error>> {
+            let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+            drop(test_rx);
+            test_tx.send(42)?;
+            Ok(42)
+        }
+        let err = round_trip().unwrap_err();
+        assert_eq!(err.to_string(), "sending on a channel with no receiver left");
+
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(1);
+        let _ = test_tx.send(42);
+        assert_eq!(test_tx.try_send(43).unwrap_err().into_inner(), 43);
+        drop(test_tx);
+        assert_eq!(test_rx.recv().unwrap(), 42);
+        assert_eq!(test_rx.recv().unwrap_err().to_string(), "receiving on an empty channel with no sender left");
     }
 
     #[test]
@@ -238,4 +3628,86 @@ mod tests{
         drop(test_rx);
         assert_eq!(test_tx.send(42).unwrap_err().0, 42);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn blocked_tx_err_for_rx_dropped() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(1);
+        let _ = test_tx.send(42);
+
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                drop(test_rx);
+            });
+            // blocks on the full channel until the receiver is dropped, then gets the value back
+            assert_eq!(test_tx.send(43).unwrap_err().0, 43);
+        });
+    }
+}
+// model checked by loom, which runs every test over all the interleavings of its threads that make a
+// difference, as in `RUSTFLAGS="--cfg loom" cargo test --release --lib loom_tests`
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use loom::thread;
+
+    use super::tx_rx_channel::{self, NoMoreReceiverErr, NoMoreSenderErr};
+
+    #[test]
+    fn recv_woken_by_send_and_by_drop_of_tx() {
+        loom::model(|| {
+            let (test_tx, test_rx) = tx_rx_channel::channel::<u32>();
+            let sender = thread::spawn(move || {
+                test_tx.send(42).unwrap();
+            });
+            assert_eq!(test_rx.recv(), Ok(42));
+            assert_eq!(test_rx.recv(), Err(NoMoreSenderErr));
+            sender.join().unwrap();
+        });
+    }
+
+    #[test]
+    fn each_msg_once_in_order_per_tx() {
+        // bounding the preemptions keeps the three threads from blowing up the number of interleavings,
+        // the usual bugs taking no more than a couple of preemptions to show up
+        let mut builder = loom::model::Builder::new();
+        builder.preemption_bound = Some(2);
+        builder.check(|| {
+            let (test_tx, test_rx) = tx_rx_channel::channel::<(u32, u32)>();
+            let senders: Vec<_> = (0..2)
+                .map(|sender| {
+                    let test_tx = test_tx.clone();
+                    thread::spawn(move || {
+                        test_tx.send((sender, 0)).unwrap();
+                        test_tx.send((sender, 1)).unwrap();
+                    })
+                })
+                .collect();
+            drop(test_tx);
+            let received: Vec<(u32, u32)> = std::iter::from_fn(|| test_rx.recv().ok()).collect();
+            senders.into_iter().for_each(|sender| sender.join().unwrap());
+            assert_eq!(received.len(), 4);
+            for sender in 0..2 {
+                let seq: Vec<u32> = received.iter().filter(|(s, _)| *s == sender).map(|(_, m)| *m).collect();
+                assert_eq!(seq, vec![0, 1]);
+            }
+        });
+    }
+
+    #[test]
+    fn blocked_tx_woken_by_recv_and_by_drop_of_rx() {
+        loom::model(|| {
+            let (test_tx, test_rx) = tx_rx_channel::sync_channel::<u32>(1);
+            let receiver = thread::spawn(move || {
+                assert_eq!(test_rx.recv(), Ok(41));
+            });
+            test_tx.send(41).unwrap();
+            // either lands in the slot freed by the recv or is handed back once the receiver is gone,
+            // but never leaves the sender blocked for good
+            match test_tx.send(42) {
+                Ok(()) | Err(NoMoreReceiverErr(42)) => {},
+                Err(err) => panic!("unexpected {err:?}"),
+            }
+            receiver.join().unwrap();
+        });
+    }
+}
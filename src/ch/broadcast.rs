@@ -0,0 +1,224 @@
+// broadcast flavor of the channel, where every receiver gets its own clone of each msg sent, as
+// opposed to the receivers competing for the msgs in the mpmc setup of tx_rx_channel
+//
+// the msgs live in one ring buffer shared by all the receivers, each one keeping a cursor of its own
+// into it. the buffer keeps only the latest `capacity` msgs, so a receiver that falls too far behind
+// finds that the msgs it hasn't seen yet have been overwritten, which is reported as a lag
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Condvar;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+
+use super::tx_rx_channel::NoMoreReceiverErr;
+
+pub struct Sender<T> {
+    shared_inner: Arc<SharedInner<T>>,
+}
+
+impl<T> Sender<T> {
+    /// hands the msg out to every receiver currently subscribed, returning how many of them there are
+    pub fn send(&self, value: T) -> Result<usize, NoMoreReceiverErr<T>> {
+        let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+        if shared_mut_data_guard.receiver_cnt == 0 {
+            return Err(NoMoreReceiverErr(value));
+        }
+        shared_mut_data_guard.ring.push_back(value);
+        shared_mut_data_guard.next_seq += 1;
+        // unlike a bounded tx_rx_channel, a full ring never blocks the sender, the oldest msg is
+        // overwritten instead, whether or not every receiver has seen it
+        if shared_mut_data_guard.ring.len() > shared_mut_data_guard.capacity {
+            shared_mut_data_guard.ring.pop_front();
+            shared_mut_data_guard.head_seq += 1;
+        }
+        let receiver_cnt = shared_mut_data_guard.receiver_cnt;
+        drop(shared_mut_data_guard);
+        // each msg concerns every receiver, unlike the notify_one for the competing receivers of tx_rx_channel
+        self.shared_inner.recv_wakeup_flag.notify_all();
+        Ok(receiver_cnt)
+    }
+
+    /// a new receiver only gets the msgs sent after it subscribes
+    pub fn subscribe(&self) -> Receiver<T> {
+        let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+        shared_mut_data_guard.receiver_cnt += 1;
+        Receiver {
+            shared_inner: Arc::clone(&self.shared_inner),
+            next_seq: shared_mut_data_guard.next_seq,
+        }
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared_inner.inner_mut_data.lock().unwrap().sender_cnt += 1;
+        Sender {
+            shared_inner: Arc::clone(&self.shared_inner)
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut inner_mut_data_lock = self.shared_inner.inner_mut_data.lock().unwrap();
+        inner_mut_data_lock.sender_cnt -= 1;
+        if inner_mut_data_lock.sender_cnt == 0 {
+            drop(inner_mut_data_lock);
+            self.shared_inner.recv_wakeup_flag.notify_all();
+        }
+    }
+}
+
+pub struct Receiver<T> {
+    shared_inner: Arc<SharedInner<T>>,
+    // the sequence number of the next msg this receiver is due to receive
+    next_seq: u64,
+}
+
+/// the reasons a recv on a broadcast channel can come back without a msg
+#[derive(Debug, PartialEq, Eq)]
+pub enum RecvError {
+    /// the receiver fell behind by more than the capacity and this many msgs were overwritten before
+    /// it got to them. the cursor is moved up to the oldest msg still around, so the next recv picks
+    /// up from there
+    Lagged(u64),
+    /// all the senders are gone and the receiver has seen every msg ever sent
+    Closed,
+}
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecvError::Lagged(skipped_cnt) => write!(f, "receiver lagged behind, missing {} msgs", skipped_cnt),
+            RecvError::Closed => f.write_str("receiving on a drained channel with no sender left"),
+        }
+    }
+}
+
+impl Error for RecvError {}
+
+impl<T: Clone> Receiver<T> {
+    pub fn recv(&mut self) -> Result<T, RecvError> {
+        let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+        loop {
+            if self.next_seq < shared_mut_data_guard.head_seq {
+                let lagged = shared_mut_data_guard.head_seq - self.next_seq;
+                self.next_seq = shared_mut_data_guard.head_seq;
+                return Err(RecvError::Lagged(lagged));
+            }
+            if self.next_seq < shared_mut_data_guard.next_seq {
+                let index = (self.next_seq - shared_mut_data_guard.head_seq) as usize;
+                self.next_seq += 1;
+                // the msg stays in the ring for the other receivers, so this one gets a clone
+                return Ok(shared_mut_data_guard.ring[index].clone());
+            }
+            if shared_mut_data_guard.sender_cnt == 0 {
+                return Err(RecvError::Closed);
+            }
+            shared_mut_data_guard = self.shared_inner.recv_wakeup_flag.wait(shared_mut_data_guard).unwrap();
+        }
+    }
+}
+
+/// the clone of a receiver starts off at the same position as the original one
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.shared_inner.inner_mut_data.lock().unwrap().receiver_cnt += 1;
+        Receiver {
+            shared_inner: Arc::clone(&self.shared_inner),
+            next_seq: self.next_seq,
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared_inner.inner_mut_data.lock().unwrap().receiver_cnt -= 1;
+    }
+}
+
+struct SharedInner<T> {
+    inner_mut_data: Mutex<SharedInnerMut<T>>,
+    recv_wakeup_flag: Condvar,
+}
+
+struct SharedInnerMut<T> {
+    // the latest msgs sent, where the msg at the front has the sequence number head_seq, and the one
+    // at the back has the sequence number next_seq - 1
+    ring: VecDeque<T>,
+    capacity: usize,
+    head_seq: u64,
+    next_seq: u64,
+    sender_cnt: usize,
+    receiver_cnt: usize,
+}
+
+pub fn channel<T: Clone>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "capacity of a broadcast channel must be non-zero");
+    let new_shared_inner = Arc::new(SharedInner {
+        inner_mut_data: Mutex::new(SharedInnerMut {
+            ring: VecDeque::with_capacity(capacity),
+            capacity,
+            head_seq: 0,
+            next_seq: 0,
+            sender_cnt: 1,
+            receiver_cnt: 1,
+        }),
+        recv_wakeup_flag: Condvar::new(),
+    });
+
+    (
+        Sender { shared_inner: Arc::clone(&new_shared_inner) },
+        Receiver { shared_inner: Arc::clone(&new_shared_inner), next_seq: 0 },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn every_rx_gets_every_msg() {
+        let (test_tx, test_rx) = channel::<u32>(16);
+        let receivers: Vec<_> = (0..3)
+            .map(|_| {
+                let mut worker_rx = test_tx.subscribe();
+                thread::spawn(move || {
+                    let mut received = Vec::new();
+                    while let Ok(msg) = worker_rx.recv() {
+                        received.push(msg);
+                    }
+                    received
+                })
+            })
+            .collect();
+        drop(test_rx);
+
+        for msg in 0..10 {
+            assert_eq!(test_tx.send(msg).ok(), Some(3));
+        }
+        drop(test_tx);
+
+        for worker in receivers {
+            assert_eq!(worker.join().unwrap(), (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn slow_rx_lagged() {
+        let (test_tx, mut test_rx) = channel::<u32>(2);
+        for msg in 0..5 {
+            let _ = test_tx.send(msg);
+        }
+        // only the latest two msgs are still in the ring
+        assert_eq!(test_rx.recv(), Err(RecvError::Lagged(3)));
+        assert_eq!(test_rx.recv(), Ok(3));
+        assert_eq!(test_rx.recv(), Ok(4));
+        drop(test_tx);
+        assert_eq!(test_rx.recv(), Err(RecvError::Closed));
+    }
+}
@@ -0,0 +1,39 @@
+// a value aligned to, and hence alone on, a cache line of its own (or rather a pair of them, as
+// neighbouring lines get prefetched together on x86_64), s.t. two such values updated by two
+// different threads don't keep invalidating each other's cache line, the so-called false sharing
+
+use std::ops::{Deref, DerefMut};
+
+#[repr(align(128))]
+#[derive(Debug, Default)]
+pub struct CachePadded<T>(pub T);
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for CachePadded<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+    use std::sync::atomic::AtomicUsize;
+
+    use super::*;
+
+    #[test]
+    fn padded_values_on_separate_lines() {
+        let pair = [CachePadded(AtomicUsize::new(0)), CachePadded(AtomicUsize::new(0))];
+        assert_eq!(mem::size_of_val(&pair[0]), 128);
+        let distance = (&pair[1] as *const _ as usize) - (&pair[0] as *const _ as usize);
+        assert!(distance >= 128);
+    }
+}
@@ -0,0 +1,48 @@
+// the receiver-like types behind ChannelReceiver::map and ChannelReceiver::filter, transforming the
+// msgs on the way out of the underlying receiver as they're received, rather than in a loop of every
+// consumer's own. being receivers in turn, they compose into pipelines
+
+use std::marker::PhantomData;
+
+use super::ChannelReceiver;
+
+/// see ChannelReceiver::map
+pub struct Map<R, F, T> {
+    pub(super) receiver: R,
+    pub(super) f: F,
+    // a receiver could be a receiver of more than one type of msg, hence the type of the msgs
+    // passed to `f` is to be pinned down
+    pub(super) msg_type: PhantomData<fn() -> T>,
+}
+
+impl<T, U, R, F> ChannelReceiver<U> for Map<R, F, T>
+where
+    R: ChannelReceiver<T>,
+    F: Fn(T) -> U,
+{
+    fn recv(&self) -> Option<U> {
+        self.receiver.recv().map(&self.f)
+    }
+}
+
+/// see ChannelReceiver::filter
+pub struct Filter<R, P> {
+    pub(super) receiver: R,
+    pub(super) predicate: P,
+}
+
+impl<T, R, P> ChannelReceiver<T> for Filter<R, P>
+where
+    R: ChannelReceiver<T>,
+    P: Fn(&T) -> bool,
+{
+    // blocks through the msgs filtered out until one makes it through
+    fn recv(&self) -> Option<T> {
+        loop {
+            let msg = self.receiver.recv()?;
+            if (self.predicate)(&msg) {
+                return Some(msg);
+            }
+        }
+    }
+}
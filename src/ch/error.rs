@@ -0,0 +1,54 @@
+// the error types of tx_rx_channel that are plain data, on core alone, for the channel of no_std to
+// share them with it. tx_rx_channel re-exports them, which is where they are named from
+
+use core::error::Error;
+use core::fmt;
+
+/// a send failing for want of a receiver, giving the unsent value back to the caller
+#[derive(Debug, PartialEq, Eq)]
+pub struct NoMoreReceiverErr<T>(pub T);
+
+impl<T> NoMoreReceiverErr<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// a recv failing for want of a sender, with no msg left to receive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoMoreSenderErr;
+
+/// the two reasons a non-blocking receive can come back empty-handed, which a poller would
+/// want to tell apart: retry later on `Empty`, but give up for good on `Disconnected`
+#[derive(Debug, PartialEq, Eq)]
+pub enum TryRecvError {
+    Empty,
+    Disconnected,
+}
+
+impl<T> fmt::Display for NoMoreReceiverErr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("sending on a channel with no receiver left")
+    }
+}
+
+impl<T: fmt::Debug> Error for NoMoreReceiverErr<T> {}
+
+impl fmt::Display for NoMoreSenderErr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("receiving on an empty channel with no sender left")
+    }
+}
+
+impl Error for NoMoreSenderErr {}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRecvError::Empty => f.write_str("receiving on an empty channel"),
+            TryRecvError::Disconnected => f.write_str("receiving on an empty channel with no sender left"),
+        }
+    }
+}
+
+impl Error for TryRecvError {}
@@ -0,0 +1,99 @@
+// topic-based pub/sub on top of tx_rx_channel, where every subscriber has a channel of its own, with
+// a sender of it registered under each of the topics subscribed to. publishing a msg on a topic sends
+// a copy of it to every sender registered under the topic, so a subscriber only ever gets to see the
+// msgs of its own topics, and gets them in the order they were published
+//
+// a subscriber leaving is a receiver dropped, which the registry finds out about by the failure of
+// the next send to it, and then forgets the sender
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use super::tx_rx_channel::{self, Receiver, Sender};
+
+/// a handle to the bus, to be cloned for every publisher and subscriber, all sharing one registry
+pub struct EventBus<K, T> {
+    // the subscribers of each topic, by the senders of their channels
+    registry: Arc<Mutex<HashMap<K, Vec<Sender<T>>>>>,
+}
+
+impl<K: Eq + Hash, T: Clone + Send + 'static> EventBus<K, T> {
+    pub fn new() -> Self {
+        EventBus {
+            registry: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// a receiver of the msgs published on any of `topics` from now on
+    pub fn subscribe(&self, topics: impl IntoIterator<Item = K>) -> Receiver<T> {
+        let (tx, rx) = tx_rx_channel::channel();
+        let mut registry = self.registry.lock().unwrap();
+        for topic in topics {
+            registry.entry(topic).or_default().push(tx.clone());
+        }
+        rx
+    }
+
+    /// sends a copy of `msg` to every subscriber of `topic`, returning how many of them there were
+    pub fn publish(&self, topic: &K, msg: T) -> usize {
+        let mut registry = self.registry.lock().unwrap();
+        let Some(subscribers) = registry.get_mut(topic) else {
+            return 0;
+        };
+        // the channels are unbounded, so no send blocks with the registry locked
+        subscribers.retain(|subscriber| subscriber.send(msg.clone()).is_ok());
+        let reached_cnt = subscribers.len();
+        if reached_cnt == 0 {
+            registry.remove(topic);
+        }
+        reached_cnt
+    }
+}
+
+impl<K: Eq + Hash, T: Clone + Send + 'static> Default for EventBus<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// derived impls would require K: Clone and T: Clone, which the shared registry has no need for
+impl<K, T> Clone for EventBus<K, T> {
+    fn clone(&self) -> Self {
+        EventBus {
+            registry: Arc::clone(&self.registry),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tx_rx_channel::TryRecvError;
+    use super::*;
+
+    #[test]
+    fn subscriber_gets_only_its_topics() {
+        let bus = EventBus::new();
+        let orders_rx = bus.subscribe(["orders"]);
+        let all_rx = bus.subscribe(["orders", "payments"]);
+        assert_eq!(bus.publish(&"orders", 1), 2);
+        assert_eq!(bus.clone().publish(&"payments", 2), 1);
+        assert_eq!(bus.publish(&"refunds", 3), 0);
+
+        assert_eq!(orders_rx.recv(), Ok(1));
+        assert_eq!(orders_rx.try_recv(), Err(TryRecvError::Empty));
+        assert_eq!(all_rx.try_iter().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn dropped_subscriber_forgotten() {
+        let bus = EventBus::new();
+        let kept_rx = bus.subscribe(["orders"]);
+        drop(bus.subscribe(["orders"]));
+        assert_eq!(bus.publish(&"orders", 1), 1);
+        assert_eq!(bus.registry.lock().unwrap()[&"orders"].len(), 1);
+        drop(kept_rx);
+        assert_eq!(bus.publish(&"orders", 2), 0);
+        assert!(bus.registry.lock().unwrap().is_empty());
+    }
+}
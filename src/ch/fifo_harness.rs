@@ -0,0 +1,69 @@
+// a check of the delivery guarantees common to every channel of this module, written once against
+// ChannelSender and ChannelReceiver: with a number of producers each sending its own tagged sequence
+// of msgs, every msg sent is to be received exactly once, and the msgs of any one producer in the
+// order that producer sent them, however they interleave with the msgs of the others
+
+use std::thread;
+
+use super::{ChannelReceiver, ChannelSender};
+
+/// runs `producer_cnt` producer threads sending `msgs_per_producer` msgs each through `test_tx`,
+/// each msg tagged with the producer and its position in the sequence of the producer, and checks
+/// what comes out of `test_rx` on the current thread. the msgs are counted in rather than received
+/// until the channel reports no more senders, which not every channel does on the drop of the senders
+pub(crate) fn check_fifo<Tx, Rx>(test_tx: &Tx, test_rx: &Rx, producer_cnt: u32, msgs_per_producer: u32)
+where
+    Tx: ChannelSender<(u32, u32)> + Sync,
+    Rx: ChannelReceiver<(u32, u32)>,
+{
+    // the position expected next of each producer
+    let mut next_seq = vec![0; producer_cnt as usize];
+    thread::scope(|scope| {
+        for producer in 0..producer_cnt {
+            scope.spawn(move || {
+                for seq in 0..msgs_per_producer {
+                    assert!(test_tx.send((producer, seq)).is_ok(), "send of {seq} by producer {producer} failed");
+                }
+            });
+        }
+        for _ in 0..producer_cnt * msgs_per_producer {
+            let (producer, seq) = test_rx.recv().expect("channel ran dry before all the msgs were received");
+            let expected = &mut next_seq[producer as usize];
+            // a msg received twice, or one skipped over, both show up as a position other than the next
+            assert_eq!(seq, *expected, "producer {producer} out of order, or a msg lost or duplicated");
+            *expected += 1;
+        }
+    });
+    assert!(next_seq.iter().all(|&seq| seq == msgs_per_producer));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{chennel_only_channel, mpsc_intrusive, parking, two_lock, tx_rx_channel};
+    use super::*;
+
+    #[test]
+    fn every_channel_impl_fifo_per_producer() {
+        let test_channel = chennel_only_channel::Channel::new();
+        check_fifo(&test_channel, &test_channel, 4, 500);
+
+        let test_channel = chennel_only_channel::Channel::with_capacity(3);
+        let (test_tx, test_rx) = test_channel.split();
+        check_fifo(&test_tx, &test_rx, 4, 500);
+
+        let (test_tx, test_rx) = tx_rx_channel::channel();
+        check_fifo(&test_tx, &test_rx, 4, 500);
+
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel(0);
+        check_fifo(&test_tx, &test_rx, 4, 500);
+
+        let (test_tx, test_rx) = two_lock::channel();
+        check_fifo(&test_tx, &test_rx, 4, 500);
+
+        let (test_tx, test_rx) = parking::channel();
+        check_fifo(&test_tx, &test_rx, 4, 500);
+
+        let (test_tx, test_rx) = mpsc_intrusive::channel();
+        check_fifo(&test_tx, &test_rx, 4, 500);
+    }
+}
@@ -0,0 +1,172 @@
+// single-threaded flavor of the unbounded channel, for event loops passing around msgs that can't
+// cross threads, e.g. ones holding an Rc. the shared state lives in an Rc<RefCell<..>> rather than an
+// Arc<Mutex<..>>, which makes the channel itself !Send, and leaves T without a Send bound
+//
+// with both ends on one thread, a recv blocking the thread would never see the msg it waits for, so
+// the waiting is left to the recv_async future and the event loop driving it, and try_recv is the
+// one synchronous way to receive
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+
+use super::tx_rx_channel::{NoMoreReceiverErr, NoMoreSenderErr, TryRecvError};
+
+pub struct Sender<T> {
+    shared: Rc<RefCell<Shared<T>>>,
+}
+
+impl<T> Sender<T> {
+    pub fn send(&self, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        let mut shared = self.shared.borrow_mut();
+        if shared.receiver_cnt == 0 {
+            return Err(NoMoreReceiverErr(value));
+        }
+        shared.msg_queue.push_back(value);
+        let recv_wakers = std::mem::take(&mut shared.recv_wakers);
+        // a waker may well poll the future right away, which borrows the shared state in turn
+        drop(shared);
+        recv_wakers.into_iter().for_each(Waker::wake);
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.borrow_mut().sender_cnt += 1;
+        Sender {
+            shared: Rc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut shared = self.shared.borrow_mut();
+        shared.sender_cnt -= 1;
+        if shared.sender_cnt == 0 {
+            let recv_wakers = std::mem::take(&mut shared.recv_wakers);
+            drop(shared);
+            recv_wakers.into_iter().for_each(Waker::wake);
+        }
+    }
+}
+
+/// as with tx_rx_channel, the clones of a receiver compete for the msgs
+pub struct Receiver<T> {
+    shared: Rc<RefCell<Shared<T>>>,
+}
+
+impl<T> Receiver<T> {
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let mut shared = self.shared.borrow_mut();
+        match shared.msg_queue.pop_front() {
+            Some(msg) => Ok(msg),
+            None if shared.sender_cnt == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// the msgs there are right now, by means of try_recv calls
+    pub fn try_iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(|| self.try_recv().ok())
+    }
+
+    /// resolves to the next msg, or to the error once all the senders are gone and the queue is
+    /// drained, for the event loop to get on with other tasks in the meantime
+    pub fn recv_async(&self) -> impl Future<Output = Result<T, NoMoreSenderErr>> + '_ {
+        std::future::poll_fn(|cx| match self.try_recv() {
+            Ok(msg) => Poll::Ready(Ok(msg)),
+            Err(TryRecvError::Disconnected) => Poll::Ready(Err(NoMoreSenderErr)),
+            Err(TryRecvError::Empty) => {
+                let mut shared = self.shared.borrow_mut();
+                if !shared.recv_wakers.iter().any(|registered| registered.will_wake(cx.waker())) {
+                    shared.recv_wakers.push(cx.waker().clone());
+                }
+                Poll::Pending
+            },
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.shared.borrow().msg_queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.shared.borrow_mut().receiver_cnt += 1;
+        Receiver {
+            shared: Rc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.borrow_mut().receiver_cnt -= 1;
+    }
+}
+
+struct Shared<T> {
+    msg_queue: VecDeque<T>,
+    sender_cnt: usize,
+    receiver_cnt: usize,
+    // the tasks awaiting a msg, all woken up by the next send, as there is no other thread to race
+    // them to it but the one task that takes the msg
+    recv_wakers: Vec<Waker>,
+}
+
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Rc::new(RefCell::new(Shared {
+        msg_queue: VecDeque::new(),
+        sender_cnt: 1,
+        receiver_cnt: 1,
+        recv_wakers: Vec::new(),
+    }));
+    (
+        Sender { shared: Rc::clone(&shared) },
+        Receiver { shared },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+    use std::task::Context;
+
+    use super::*;
+
+    #[test]
+    fn rc_msgs_through_one_thread() {
+        let (test_tx, test_rx) = channel::<Rc<RefCell<Vec<u32>>>>();
+        let shared_msg = Rc::new(RefCell::new(vec![]));
+        assert!(test_tx.send(Rc::clone(&shared_msg)).is_ok());
+        test_rx.try_recv().unwrap().borrow_mut().push(42);
+        assert_eq!(*shared_msg.borrow(), vec![42]);
+        assert_eq!(test_rx.try_recv().unwrap_err(), TryRecvError::Empty);
+        drop(test_tx);
+        assert_eq!(test_rx.try_recv().unwrap_err(), TryRecvError::Disconnected);
+    }
+
+    #[test]
+    fn recv_async_pending_until_sent() {
+        let (test_tx, test_rx) = channel::<u32>();
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut recv_future = pin!(test_rx.recv_async());
+        assert!(recv_future.as_mut().poll(&mut cx).is_pending());
+        assert!(test_tx.send(42).is_ok());
+        assert_eq!(recv_future.as_mut().poll(&mut cx), Poll::Ready(Ok(42)));
+
+        let mut recv_future = pin!(test_rx.recv_async());
+        assert!(recv_future.as_mut().poll(&mut cx).is_pending());
+        drop(test_tx);
+        assert_eq!(recv_future.as_mut().poll(&mut cx), Poll::Ready(Err(NoMoreSenderErr)));
+    }
+}
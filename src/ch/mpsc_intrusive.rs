@@ -0,0 +1,269 @@
+// mpsc flavor of the unbounded channel where a send takes no lock at all: every msg goes into a node
+// of its own, linked into a singly linked list by an atomic swap of the pointer to the last node, after
+// Dmitry Vyukov's intrusive mpsc queue, which is also what the mpsc channel of tokio is built on
+//
+// a sender swaps its node in as the new last node, and only then links the former last node to it,
+// s.t. for a moment the list is cut in two, with the new node not reachable yet from the front. the
+// one receiver, the only one ever touching the front of the list, tells that apart from an empty list
+// by the last node not being the one at the front, and waits for the link to show up
+//
+// as in the two-lock queue, the node at the front is a dummy one, whose successor holds the next msg
+// to receive, and which the receiver frees once it moves on to that successor. blocking is left to
+// parking the receiver, as in the parking channel, for which a sender only goes through a lock when
+// the receiver is actually parked
+
+use std::cell::{Cell, UnsafeCell};
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, Thread};
+
+use super::tx_rx_channel::{NoMoreReceiverErr, NoMoreSenderErr, TryRecvError};
+use super::{ChannelReceiver, ChannelSender};
+
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    pub fn send(&self, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        if !self.shared.receiver_live.load(Ordering::Acquire) {
+            return Err(NoMoreReceiverErr(value));
+        }
+        let node = Box::into_raw(Box::new(Node {
+            value: Some(value),
+            next: AtomicPtr::new(ptr::null_mut()),
+        }));
+        let prev = self.shared.last.swap(node, Ordering::AcqRel);
+        // SAFETY: the node swapped out is still in the list, as the receiver only frees a node once
+        // it's moved on to its successor, which this node has none of until right here
+        unsafe { (*prev).next.store(node, Ordering::Release) };
+        self.shared.unpark_receiver();
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.sender_cnt.fetch_add(1, Ordering::Relaxed);
+        Sender {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        // the last sender gone is news to a parked receiver just like a new msg
+        if self.shared.sender_cnt.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.shared.unpark_receiver();
+        }
+    }
+}
+
+/// the one and only receiver, which is neither Clone nor Sync, the front of the list being its alone
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    not_sync: PhantomData<Cell<()>>,
+}
+
+impl<T> Receiver<T> {
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        // the count of senders is looked at before the list, s.t. a msg sent right before the last
+        // sender is dropped is still found in there
+        let sender_live = self.shared.sender_cnt.load(Ordering::Acquire) > 0;
+        match self.pop() {
+            Some(msg) => Ok(msg),
+            None if sender_live => Err(TryRecvError::Empty),
+            None => Err(TryRecvError::Disconnected),
+        }
+    }
+
+    pub fn recv(&self) -> Result<T, NoMoreSenderErr> {
+        loop {
+            match self.try_recv() {
+                Ok(msg) => return Ok(msg),
+                Err(TryRecvError::Disconnected) => return Err(NoMoreSenderErr),
+                Err(TryRecvError::Empty) => {},
+            }
+            // the receiver may have moved to another thread since it last parked
+            *self.shared.receiver_thread.lock().unwrap() = Some(thread::current());
+            self.shared.receiver_parked.store(true, Ordering::SeqCst);
+            // a msg swapped in before the flag was raised is seen here, and one swapped in after it
+            // is followed by an unpark
+            let nothing_new = self.shared.last.load(Ordering::SeqCst) == self.front()
+                && self.shared.sender_cnt.load(Ordering::SeqCst) > 0;
+            if nothing_new {
+                // park can return spuriously, which the next round of the loop takes care of
+                thread::park();
+            }
+            self.shared.receiver_parked.store(false, Ordering::SeqCst);
+        }
+    }
+
+    fn front(&self) -> *mut Node<T> {
+        // SAFETY: the front is only ever accessed by the receiver, which is not Sync
+        unsafe { *self.shared.front.get() }
+    }
+
+    fn pop(&self) -> Option<T> {
+        let dummy = self.front();
+        loop {
+            // SAFETY: the dummy node at the front is alive until the receiver moves past it
+            let next = unsafe { (*dummy).next.load(Ordering::Acquire) };
+            if !next.is_null() {
+                // SAFETY: a successor is linked in fully initialized, and its value is only ever
+                // touched by the receiver. taking it turns the node into the new dummy one
+                let msg = unsafe { (*next).value.take() };
+                // SAFETY: see front
+                unsafe { *self.shared.front.get() = next };
+                // SAFETY: with a successor, the old dummy node is no longer the last one, s.t. no
+                // sender can get to it any more, nor the receiver once the front has moved past it
+                drop(unsafe { Box::from_raw(dummy) });
+                return msg;
+            }
+            if self.shared.last.load(Ordering::Acquire) == dummy {
+                return None;
+            }
+            // a sender is in between the swap and the link, which is a matter of a few instructions
+            std::hint::spin_loop();
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.receiver_live.store(false, Ordering::Release);
+    }
+}
+
+impl<T> ChannelSender<T> for Sender<T> {
+    fn send(&self, value: T) -> Result<(), T> {
+        Sender::send(self, value).map_err(NoMoreReceiverErr::into_inner)
+    }
+}
+
+impl<T> ChannelReceiver<T> for Receiver<T> {
+    fn recv(&self) -> Option<T> {
+        Receiver::recv(self).ok()
+    }
+}
+
+struct Node<T> {
+    // None for the dummy node at the front
+    value: Option<T>,
+    next: AtomicPtr<Node<T>>,
+}
+
+struct Shared<T> {
+    // the dummy node, whose successor is the next msg to receive, only ever touched by the receiver
+    front: UnsafeCell<*mut Node<T>>,
+    // the node last swapped in by a sender, the dummy node itself when the list is empty
+    last: AtomicPtr<Node<T>>,
+    sender_cnt: AtomicUsize,
+    receiver_live: AtomicBool,
+    // raised by the receiver right before it parks, and swapped back down by whichever sender gets
+    // to unpark it
+    receiver_parked: AtomicBool,
+    receiver_thread: Mutex<Option<Thread>>,
+}
+
+// SAFETY: the front is only accessed by the one receiver, the rest of the nodes through atomics, and
+// the msgs crossing over to the receiving thread need to be Send
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    fn unpark_receiver(&self) {
+        // no parked receiver, no lock to take, which is the whole cost of a send on the fast path
+        if self.receiver_parked.swap(false, Ordering::SeqCst) {
+            if let Some(receiver_thread) = self.receiver_thread.lock().unwrap().as_ref() {
+                receiver_thread.unpark();
+            }
+        }
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        // both ends are gone by now, leaving the nodes of the msgs never received to be freed here
+        let mut node = *self.front.get_mut();
+        while !node.is_null() {
+            // SAFETY: every node from the front onwards is owned by the list
+            let boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next.load(Ordering::Relaxed);
+        }
+    }
+}
+
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let dummy = Box::into_raw(Box::new(Node {
+        value: None,
+        next: AtomicPtr::new(ptr::null_mut()),
+    }));
+    let shared = Arc::new(Shared {
+        front: UnsafeCell::new(dummy),
+        last: AtomicPtr::new(dummy),
+        sender_cnt: AtomicUsize::new(1),
+        receiver_live: AtomicBool::new(true),
+        receiver_parked: AtomicBool::new(false),
+        receiver_thread: Mutex::new(None),
+    });
+    (
+        Sender { shared: Arc::clone(&shared) },
+        Receiver {
+            shared,
+            not_sync: PhantomData,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn lock_free_tx_each_msg_once_in_order_per_tx() {
+        let (test_tx, test_rx) = channel::<(u32, u32)>();
+        let producers: Vec<_> = (0..3)
+            .map(|producer| {
+                let test_tx = test_tx.clone();
+                thread::spawn(move || {
+                    for msg in 0..100 {
+                        let _ = test_tx.send((producer, msg));
+                    }
+                })
+            })
+            .collect();
+        drop(test_tx);
+        let received: Vec<(u32, u32)> = std::iter::from_fn(|| test_rx.recv().ok()).collect();
+        producers.into_iter().for_each(|producer| producer.join().unwrap());
+        for producer in 0..3 {
+            let seq: Vec<u32> = received.iter().filter(|(p, _)| *p == producer).map(|(_, m)| *m).collect();
+            assert_eq!(seq, (0..100).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn parked_rx_woken_and_unreceived_msgs_freed() {
+        let (test_tx, test_rx) = channel::<Box<u32>>();
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(50));
+                let _ = test_tx.send(Box::new(42));
+            });
+            assert_eq!(*test_rx.recv().unwrap(), 42);
+        });
+        let _ = test_tx.send(Box::new(43));
+        let _ = test_tx.send(Box::new(44));
+        assert_eq!(test_rx.try_recv().map(|msg| *msg), Ok(43));
+        drop(test_tx);
+        assert_eq!(test_rx.try_recv().map(|msg| *msg), Ok(44));
+        assert_eq!(test_rx.try_recv().unwrap_err(), TryRecvError::Disconnected);
+        let (test_tx, test_rx) = channel::<Box<u32>>();
+        let _ = test_tx.send(Box::new(45));
+    }
+}
@@ -0,0 +1,202 @@
+// mpsc flavor of the unbounded channel that gets by on core and alloc alone, for the targets without
+// an OS to block a thread on, as in embedded setups. there being no Mutex nor Condvar, the queue is
+// behind a spin lock, and a receiver waiting for a msg spins on it too
+//
+// the API is the one of tx_rx_channel, down to its error types, which are plain data on core alone,
+// in ch::error for the two of them to share
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use core::cell::UnsafeCell;
+use core::hint;
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use super::error::{NoMoreReceiverErr, NoMoreSenderErr, TryRecvError};
+
+/// a lock held by spinning on an atomic flag, rather than by blocking the thread
+pub struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: the value is only ever accessed through the one guard there is at a time, which may be on
+// any thread, hence T: Send
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+impl<T> SpinLock<T> {
+    pub const fn new(value: T) -> Self {
+        SpinLock {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    pub fn lock(&self) -> SpinLockGuard<'_, T> {
+        // the swap, with its exclusive access to the cache line, is only tried once a plain load finds
+        // the lock free, s.t. the threads spinning don't keep taking the line from the holder
+        while self.locked.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed).is_err() {
+            while self.locked.load(Ordering::Relaxed) {
+                hint::spin_loop();
+            }
+        }
+        SpinLockGuard { lock: self }
+    }
+}
+
+pub struct SpinLockGuard<'a, T> {
+    lock: &'a SpinLock<T>,
+}
+
+impl<T> Deref for SpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: the guard stands for the lock being held, the value is not accessed otherwise
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for SpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: see deref
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for SpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.locked.store(false, Ordering::Release);
+    }
+}
+
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    pub fn send(&self, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        if !self.shared.receiver_live.load(Ordering::Acquire) {
+            return Err(NoMoreReceiverErr(value));
+        }
+        self.shared.msg_queue.lock().push_back(value);
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.sender_cnt.fetch_add(1, Ordering::Relaxed);
+        Sender {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.shared.sender_cnt.fetch_sub(1, Ordering::Release);
+    }
+}
+
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        // the count of senders is looked at before the queue, s.t. a msg sent right before the last
+        // sender is dropped is still found in there
+        let sender_live = self.shared.sender_cnt.load(Ordering::Acquire) > 0;
+        match self.shared.msg_queue.lock().pop_front() {
+            Some(msg) => Ok(msg),
+            None if sender_live => Err(TryRecvError::Empty),
+            None => Err(TryRecvError::Disconnected),
+        }
+    }
+
+    /// busy-waits until there is a msg, or no sender left to send one, with no OS to park the
+    /// thread on: the core it runs on is taken for as long as the channel stays empty, which only
+    /// suits a receiver with a core of its own. one that shares its core, e.g. with the senders, or
+    /// has other work to do in the meantime, is better off polling try_recv in its own loop
+    ///
+    /// the spins in between two looks at the queue double up to MAX_BACKOFF_SPINS, s.t. a receiver
+    /// waiting long doesn't keep taking the spin lock from the senders it waits for
+    pub fn recv(&self) -> Result<T, NoMoreSenderErr> {
+        let mut backoff_spins = 1;
+        loop {
+            match self.try_recv() {
+                Ok(msg) => return Ok(msg),
+                Err(TryRecvError::Disconnected) => return Err(NoMoreSenderErr),
+                Err(TryRecvError::Empty) => {
+                    (0..backoff_spins).for_each(|_| hint::spin_loop());
+                    backoff_spins = (backoff_spins * 2).min(MAX_BACKOFF_SPINS);
+                },
+            }
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.receiver_live.store(false, Ordering::Release);
+    }
+}
+
+// the most spins of a waiting recv in between two looks at the queue
+const MAX_BACKOFF_SPINS: u32 = 1 << 10;
+
+struct Shared<T> {
+    msg_queue: SpinLock<VecDeque<T>>,
+    sender_cnt: AtomicUsize,
+    receiver_live: AtomicBool,
+}
+
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        msg_queue: SpinLock::new(VecDeque::new()),
+        sender_cnt: AtomicUsize::new(1),
+        receiver_live: AtomicBool::new(true),
+    });
+    (
+        Sender { shared: Arc::clone(&shared) },
+        Receiver { shared },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn spinning_rx_gets_every_msg_then_disconnected() {
+        let (test_tx, test_rx) = channel::<u32>();
+        let producers: Vec<_> = (0..2)
+            .map(|producer| {
+                let test_tx = test_tx.clone();
+                thread::spawn(move || {
+                    for msg in 0..100 {
+                        let _ = test_tx.send(producer * 100 + msg);
+                    }
+                })
+            })
+            .collect();
+        drop(test_tx);
+        let mut received: Vec<u32> = std::iter::from_fn(|| test_rx.recv().ok()).collect();
+        producers.into_iter().for_each(|producer| producer.join().unwrap());
+        received.sort();
+        assert_eq!(received, (0..200).collect::<Vec<_>>());
+        assert_eq!(test_rx.try_recv(), Err(TryRecvError::Disconnected));
+    }
+
+    #[test]
+    fn tx_err_for_no_rx() {
+        let (test_tx, test_rx) = channel::<u32>();
+        drop(test_rx);
+        assert_eq!(test_tx.send(42).unwrap_err().into_inner(), 42);
+    }
+}
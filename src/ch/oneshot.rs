@@ -0,0 +1,119 @@
+// single-use flavor of the channel, carrying exactly one msg from one Sender to one Receiver, where
+// both `send` and `recv` consume their handle s.t. the type system rules out any second use
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Condvar;
+
+use super::tx_rx_channel::{NoMoreReceiverErr, NoMoreSenderErr};
+
+pub struct Sender<T> {
+    shared_inner: Arc<SharedInner<T>>,
+}
+
+impl<T> Sender<T> {
+    pub fn send(self, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+        if !shared_mut_data_guard.receiver_live {
+            return Err(NoMoreReceiverErr(value));
+        }
+        shared_mut_data_guard.slot = Some(value);
+        drop(shared_mut_data_guard);
+        self.shared_inner.recv_wakeup_flag.notify_one();
+        // self is dropped on the way out, which would flip sender_live, but the msg is already in the
+        // slot for the receiver to find before it ever looks at the flag
+        Ok(())
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.shared_inner.inner_mut_data.lock().unwrap().sender_live = false;
+        self.shared_inner.recv_wakeup_flag.notify_one();
+    }
+}
+
+pub struct Receiver<T> {
+    shared_inner: Arc<SharedInner<T>>,
+}
+
+impl<T> Receiver<T> {
+    pub fn recv(self) -> Result<T, NoMoreSenderErr> {
+        let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+        loop {
+            if let Some(msg) = shared_mut_data_guard.slot.take() {
+                return Ok(msg);
+            }
+            if !shared_mut_data_guard.sender_live {
+                return Err(NoMoreSenderErr);
+            }
+            shared_mut_data_guard = self.shared_inner.recv_wakeup_flag.wait(shared_mut_data_guard).unwrap();
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared_inner.inner_mut_data.lock().unwrap().receiver_live = false;
+    }
+}
+
+struct SharedInner<T> {
+    inner_mut_data: Mutex<SharedInnerMut<T>>,
+    recv_wakeup_flag: Condvar,
+}
+
+// there being at most one msg ever, a single slot takes the place of the msg queue, and with exactly
+// one handle on each end, a flag per end takes the place of the counts
+struct SharedInnerMut<T> {
+    slot: Option<T>,
+    sender_live: bool,
+    receiver_live: bool,
+}
+
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let new_shared_inner = Arc::new(SharedInner {
+        inner_mut_data: Mutex::new(SharedInnerMut {
+            slot: None,
+            sender_live: true,
+            receiver_live: true,
+        }),
+        recv_wakeup_flag: Condvar::new(),
+    });
+
+    (
+        Sender { shared_inner: Arc::clone(&new_shared_inner) },
+        Receiver { shared_inner: Arc::clone(&new_shared_inner) },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn oneshot_send_recv_across_threads() {
+        let (test_tx, test_rx) = channel::<u32>();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            let _ = test_tx.send(42);
+        });
+        assert_eq!(test_rx.recv().unwrap(), 42);
+    }
+
+    #[test]
+    fn oneshot_rx_err_for_tx_dropped_unsent() {
+        let (test_tx, test_rx) = channel::<u32>();
+        drop(test_tx);
+        assert!(test_rx.recv().is_err());
+    }
+
+    #[test]
+    fn oneshot_tx_err_for_no_rx() {
+        let (test_tx, test_rx) = channel::<u32>();
+        drop(test_rx);
+        assert_eq!(test_tx.send(42).unwrap_err().0, 42);
+    }
+}
@@ -0,0 +1,194 @@
+// mpsc flavor of the unbounded channel whose one receiver blocks by parking its thread rather than
+// waiting on a cond var, s.t. a sender wakes it up directly with Thread::unpark
+//
+// the queue itself is still behind a Mutex, but no sender ever has to touch a cond var: the receiver
+// about to block leaves its Thread handle in the channel and raises the parked flag, and a sender
+// only goes for the handle when it swaps the flag back down. since the receiver checks the queue once
+// more after raising the flag, a msg pushed in between is either seen by that check or followed by
+// an unpark, and an unpark coming before the park makes the park return right away, as the token it
+// leaves behind isn't lost
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, Thread};
+
+use super::tx_rx_channel::{NoMoreReceiverErr, NoMoreSenderErr, TryRecvError};
+use super::{ChannelReceiver, ChannelSender};
+
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    pub fn send(&self, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        if !self.shared.receiver_live.load(Ordering::Acquire) {
+            return Err(NoMoreReceiverErr(value));
+        }
+        self.shared.msg_queue.lock().unwrap().push_back(value);
+        self.shared.unpark_receiver();
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.sender_cnt.fetch_add(1, Ordering::Relaxed);
+        Sender {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        // the last sender gone is news to a parked receiver just like a new msg
+        if self.shared.sender_cnt.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.shared.unpark_receiver();
+        }
+    }
+}
+
+/// the one and only receiver, which is not Clone, as there is a single Thread handle to unpark
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        // the count of senders is looked at before the queue, s.t. a msg sent right before the last
+        // sender is dropped is still found in there
+        let sender_live = self.shared.sender_cnt.load(Ordering::Acquire) > 0;
+        match self.shared.msg_queue.lock().unwrap().pop_front() {
+            Some(msg) => Ok(msg),
+            None if sender_live => Err(TryRecvError::Empty),
+            None => Err(TryRecvError::Disconnected),
+        }
+    }
+
+    pub fn recv(&self) -> Result<T, NoMoreSenderErr> {
+        loop {
+            match self.try_recv() {
+                Ok(msg) => return Ok(msg),
+                Err(TryRecvError::Disconnected) => return Err(NoMoreSenderErr),
+                Err(TryRecvError::Empty) => {},
+            }
+            // the receiver may have moved to another thread since it last parked
+            *self.shared.receiver_thread.lock().unwrap() = Some(thread::current());
+            self.shared.receiver_parked.store(true, Ordering::SeqCst);
+            // the check once more after raising the flag closes the window for a msg, or the drop of
+            // the last sender, to slip in right before it without an unpark to follow
+            let nothing_new = self.shared.msg_queue.lock().unwrap().is_empty()
+                && self.shared.sender_cnt.load(Ordering::SeqCst) > 0;
+            if nothing_new {
+                // park can return spuriously, which the next round of the loop takes care of
+                thread::park();
+            }
+            self.shared.receiver_parked.store(false, Ordering::SeqCst);
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.receiver_live.store(false, Ordering::Release);
+    }
+}
+
+impl<T> ChannelSender<T> for Sender<T> {
+    fn send(&self, value: T) -> Result<(), T> {
+        Sender::send(self, value).map_err(NoMoreReceiverErr::into_inner)
+    }
+}
+
+impl<T> ChannelReceiver<T> for Receiver<T> {
+    fn recv(&self) -> Option<T> {
+        Receiver::recv(self).ok()
+    }
+}
+
+struct Shared<T> {
+    msg_queue: Mutex<VecDeque<T>>,
+    sender_cnt: AtomicUsize,
+    receiver_live: AtomicBool,
+    // raised by the receiver right before it parks, and swapped back down by whichever sender gets
+    // to unpark it
+    receiver_parked: AtomicBool,
+    receiver_thread: Mutex<Option<Thread>>,
+}
+
+impl<T> Shared<T> {
+    fn unpark_receiver(&self) {
+        // no parked receiver, no handle to look up, which is the whole cost of a send on the fast path
+        if self.receiver_parked.swap(false, Ordering::SeqCst) {
+            if let Some(receiver_thread) = self.receiver_thread.lock().unwrap().as_ref() {
+                receiver_thread.unpark();
+            }
+        }
+    }
+}
+
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        msg_queue: Mutex::new(VecDeque::new()),
+        sender_cnt: AtomicUsize::new(1),
+        receiver_live: AtomicBool::new(true),
+        receiver_parked: AtomicBool::new(false),
+        receiver_thread: Mutex::new(None),
+    });
+    (
+        Sender { shared: Arc::clone(&shared) },
+        Receiver { shared },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn parked_rx_woken_by_each_tx() {
+        let (test_tx, test_rx) = channel::<u32>();
+        let producers: Vec<_> = (0..3)
+            .map(|producer| {
+                let test_tx = test_tx.clone();
+                thread::spawn(move || {
+                    for msg in 0..100 {
+                        // give the receiver time to park now and then
+                        if msg % 25 == 0 {
+                            thread::sleep(Duration::from_millis(5));
+                        }
+                        let _ = test_tx.send(producer * 100 + msg);
+                    }
+                })
+            })
+            .collect();
+        drop(test_tx);
+
+        let mut received: Vec<u32> = std::iter::from_fn(|| test_rx.recv().ok()).collect();
+        producers.into_iter().for_each(|producer| producer.join().unwrap());
+        received.sort();
+        assert_eq!(received, (0..300).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn parked_rx_woken_by_last_tx_dropped() {
+        let (test_tx, test_rx) = channel::<u32>();
+        let dropper = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            drop(test_tx);
+        });
+        assert!(test_rx.recv().is_err());
+        dropper.join().unwrap();
+    }
+
+    #[test]
+    fn tx_err_for_no_rx() {
+        let (test_tx, test_rx) = channel::<u32>();
+        drop(test_rx);
+        assert_eq!(test_tx.send(42).unwrap_err().0, 42);
+    }
+}
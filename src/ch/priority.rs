@@ -0,0 +1,322 @@
+// priority flavor of the channel, where recv always hands out the pending msg with the highest
+// priority, rather than the one sent the earliest
+//
+// the msg queue is a BinaryHeap instead of a VecDeque, with each msg stamped with a sequence number
+// on the way in, s.t. msgs of the same priority still come out in the order they were sent
+//
+// the lanes flavor further down gives the priority to the sender rather than to every msg, each
+// sender sending into the lane of its priority class, e.g. control msgs on a lane of their own ahead
+// of bulk data. the lanes being drained strictly by priority, a busy lane would keep the ones below
+// it waiting forever, hence the starvation limit, past which the oldest msg of all goes first
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, BinaryHeap, VecDeque};
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Condvar;
+
+use super::tx_rx_channel::{NoMoreReceiverErr, NoMoreSenderErr};
+
+pub struct Sender<T> {
+    shared_inner: Arc<SharedInner<T>>,
+}
+
+impl<T> Sender<T> {
+    /// the greater the priority, the sooner the msg is received
+    pub fn send(&self, value: T, priority: u32) -> Result<(), NoMoreReceiverErr<T>> {
+        let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+        if shared_mut_data_guard.receiver_cnt == 0 {
+            return Err(NoMoreReceiverErr(value));
+        }
+        let seq = shared_mut_data_guard.next_seq;
+        shared_mut_data_guard.next_seq += 1;
+        shared_mut_data_guard.msg_heap.push(PrioritizedMsg { priority, seq: Reverse(seq), value });
+        drop(shared_mut_data_guard);
+        self.shared_inner.recv_wakeup_flag.notify_one();
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared_inner.inner_mut_data.lock().unwrap().sender_cnt += 1;
+        Sender {
+            shared_inner: Arc::clone(&self.shared_inner)
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut inner_mut_data_lock = self.shared_inner.inner_mut_data.lock().unwrap();
+        inner_mut_data_lock.sender_cnt -= 1;
+        if inner_mut_data_lock.sender_cnt == 0 {
+            drop(inner_mut_data_lock);
+            self.shared_inner.recv_wakeup_flag.notify_all();
+        }
+    }
+}
+
+pub struct Receiver<T> {
+    shared_inner: Arc<SharedInner<T>>,
+}
+
+impl<T> Receiver<T> {
+    pub fn recv(&self) -> Result<T, NoMoreSenderErr> {
+        let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+        loop {
+            if let Some(prioritized_msg) = shared_mut_data_guard.msg_heap.pop() {
+                return Ok(prioritized_msg.value);
+            }
+            if shared_mut_data_guard.sender_cnt == 0 {
+                return Err(NoMoreSenderErr);
+            }
+            shared_mut_data_guard = self.shared_inner.recv_wakeup_flag.wait(shared_mut_data_guard).unwrap();
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared_inner.inner_mut_data.lock().unwrap().receiver_cnt -= 1;
+    }
+}
+
+// a msg as stored in the heap, ordered by priority first, and then by sequence number in reverse,
+// s.t. the earlier of two msgs of the same priority is the greater one, i.e. popped first from the
+// max-heap. the ordering deliberately ignores the value, which needn't be Ord at all
+struct PrioritizedMsg<T> {
+    priority: u32,
+    seq: Reverse<u64>,
+    value: T,
+}
+
+impl<T> PartialEq for PrioritizedMsg<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for PrioritizedMsg<T> {}
+
+impl<T> PartialOrd for PrioritizedMsg<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for PrioritizedMsg<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.priority, self.seq).cmp(&(other.priority, other.seq))
+    }
+}
+
+struct SharedInner<T> {
+    inner_mut_data: Mutex<SharedInnerMut<T>>,
+    recv_wakeup_flag: Condvar,
+}
+
+struct SharedInnerMut<T> {
+    msg_heap: BinaryHeap<PrioritizedMsg<T>>,
+    next_seq: u64,
+    sender_cnt: usize,
+    receiver_cnt: usize,
+}
+
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let new_shared_inner = Arc::new(SharedInner {
+        inner_mut_data: Mutex::new(SharedInnerMut {
+            msg_heap: BinaryHeap::new(),
+            next_seq: 0,
+            sender_cnt: 1,
+            receiver_cnt: 1,
+        }),
+        recv_wakeup_flag: Condvar::new(),
+    });
+
+    (
+        Sender { shared_inner: Arc::clone(&new_shared_inner) },
+        Receiver { shared_inner: Arc::clone(&new_shared_inner) },
+    )
+}
+
+pub struct LaneSender<T> {
+    shared_inner: Arc<LaneSharedInner<T>>,
+    lane: u32,
+}
+
+impl<T> LaneSender<T> {
+    pub fn send(&self, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+        if shared_mut_data_guard.receiver_cnt == 0 {
+            return Err(NoMoreReceiverErr(value));
+        }
+        let seq = shared_mut_data_guard.next_seq;
+        shared_mut_data_guard.next_seq += 1;
+        shared_mut_data_guard.lanes.entry(self.lane).or_default().push_back((seq, value));
+        drop(shared_mut_data_guard);
+        self.shared_inner.recv_wakeup_flag.notify_one();
+        Ok(())
+    }
+
+    /// the priority class of this sender, the greater the sooner its msgs are received
+    pub fn lane(&self) -> u32 {
+        self.lane
+    }
+
+    /// a sender of the same channel sending into `lane`, whereas a plain clone stays in the same lane
+    pub fn clone_with_lane(&self, lane: u32) -> LaneSender<T> {
+        self.shared_inner.inner_mut_data.lock().unwrap().sender_cnt += 1;
+        LaneSender {
+            shared_inner: Arc::clone(&self.shared_inner),
+            lane,
+        }
+    }
+}
+
+impl<T> Clone for LaneSender<T> {
+    fn clone(&self) -> Self {
+        self.clone_with_lane(self.lane)
+    }
+}
+
+impl<T> Drop for LaneSender<T> {
+    fn drop(&mut self) {
+        let mut inner_mut_data_lock = self.shared_inner.inner_mut_data.lock().unwrap();
+        inner_mut_data_lock.sender_cnt -= 1;
+        if inner_mut_data_lock.sender_cnt == 0 {
+            drop(inner_mut_data_lock);
+            self.shared_inner.recv_wakeup_flag.notify_all();
+        }
+    }
+}
+
+pub struct LaneReceiver<T> {
+    shared_inner: Arc<LaneSharedInner<T>>,
+}
+
+impl<T> LaneReceiver<T> {
+    pub fn recv(&self) -> Result<T, NoMoreSenderErr> {
+        let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+        loop {
+            if let Some(value) = shared_mut_data_guard.pop_msg() {
+                return Ok(value);
+            }
+            if shared_mut_data_guard.sender_cnt == 0 {
+                return Err(NoMoreSenderErr);
+            }
+            shared_mut_data_guard = self.shared_inner.recv_wakeup_flag.wait(shared_mut_data_guard).unwrap();
+        }
+    }
+}
+
+impl<T> Drop for LaneReceiver<T> {
+    fn drop(&mut self) {
+        self.shared_inner.inner_mut_data.lock().unwrap().receiver_cnt -= 1;
+    }
+}
+
+struct LaneSharedInner<T> {
+    inner_mut_data: Mutex<LaneSharedInnerMut<T>>,
+    recv_wakeup_flag: Condvar,
+}
+
+struct LaneSharedInnerMut<T> {
+    // the msgs of each lane by priority class, in the order they were sent, along with their sequence
+    // number across all the lanes. a lane is removed once empty
+    lanes: BTreeMap<u32, VecDeque<(u64, T)>>,
+    next_seq: u64,
+    // msgs in a row taken from the top lane while there were msgs waiting in the lanes below, and
+    // how many of them it takes for the oldest msg of all to go first
+    passed_over_cnt: usize,
+    starvation_limit: Option<usize>,
+    sender_cnt: usize,
+    receiver_cnt: usize,
+}
+
+impl<T> LaneSharedInnerMut<T> {
+    fn pop_msg(&mut self) -> Option<T> {
+        let mut lanes = self.lanes.iter().rev();
+        let (&top_lane, _) = lanes.next()?;
+        let lane = if lanes.next().is_none() {
+            self.passed_over_cnt = 0;
+            top_lane
+        } else if self.starvation_limit.is_some_and(|starvation_limit| self.passed_over_cnt >= starvation_limit) {
+            self.passed_over_cnt = 0;
+            // the lanes are few, so a look at the front of each is cheap enough
+            let oldest = self.lanes.iter().min_by_key(|(_, lane_msgs)| lane_msgs.front().map(|(seq, _)| *seq));
+            *oldest.unwrap().0
+        } else {
+            self.passed_over_cnt += 1;
+            top_lane
+        };
+        let lane_msgs = self.lanes.get_mut(&lane).unwrap();
+        let (_, value) = lane_msgs.pop_front().unwrap();
+        if lane_msgs.is_empty() {
+            self.lanes.remove(&lane);
+        }
+        Some(value)
+    }
+}
+
+/// a channel whose first sender sends into `lane`, with the senders of other lanes to be cloned off
+/// it with LaneSender::clone_with_lane. with a starvation limit, no more than that many msgs in a row
+/// are taken from the top lane while the lanes below have msgs waiting, before the oldest of those
+/// goes first
+pub fn lane_channel<T>(lane: u32, starvation_limit: Option<usize>) -> (LaneSender<T>, LaneReceiver<T>) {
+    let new_shared_inner = Arc::new(LaneSharedInner {
+        inner_mut_data: Mutex::new(LaneSharedInnerMut {
+            lanes: BTreeMap::new(),
+            next_seq: 0,
+            passed_over_cnt: 0,
+            starvation_limit,
+            sender_cnt: 1,
+            receiver_cnt: 1,
+        }),
+        recv_wakeup_flag: Condvar::new(),
+    });
+
+    (
+        LaneSender { shared_inner: Arc::clone(&new_shared_inner), lane },
+        LaneReceiver { shared_inner: new_shared_inner },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highest_priority_first_fifo_within_priority() {
+        let (test_tx, test_rx) = channel::<&str>();
+        let _ = test_tx.send("low 1", 0);
+        let _ = test_tx.send("high 1", 5);
+        let _ = test_tx.send("low 2", 0);
+        let _ = test_tx.send("high 2", 5);
+        let _ = test_tx.send("mid", 2);
+        drop(test_tx);
+
+        let received: Vec<_> = std::iter::from_fn(|| test_rx.recv().ok()).collect();
+        assert_eq!(received, vec!["high 1", "high 2", "mid", "low 1", "low 2"]);
+    }
+
+    #[test]
+    fn lanes_by_priority_with_starvation_limit() {
+        let (bulk_tx, test_rx) = lane_channel::<&str>(0, Some(2));
+        let control_tx = bulk_tx.clone_with_lane(1);
+        assert_eq!((bulk_tx.clone().lane(), control_tx.lane()), (0, 1));
+        for msg in ["bulk 1", "bulk 2"] {
+            let _ = bulk_tx.send(msg);
+        }
+        for msg in ["control 1", "control 2", "control 3", "control 4", "control 5"] {
+            let _ = control_tx.send(msg);
+        }
+        drop((bulk_tx, control_tx));
+
+        let received: Vec<_> = std::iter::from_fn(|| test_rx.recv().ok()).collect();
+        assert_eq!(
+            received,
+            vec!["control 1", "control 2", "bulk 1", "control 3", "control 4", "bulk 2", "control 5"]
+        );
+    }
+}
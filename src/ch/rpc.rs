@@ -0,0 +1,125 @@
+// the request-response idiom of actors on top of the mpsc channel, where each request travels along
+// with the sending end of a oneshot channel of its own, for the one handling the request to send the
+// response back to the one blocked waiting for it
+
+use std::error::Error;
+use std::fmt;
+
+use super::oneshot;
+use super::tx_rx_channel::{self, NoMoreSenderErr};
+
+/// the client end, which can be cloned for as many clients as needed
+pub struct Requester<Req, Resp> {
+    request_tx: tx_rx_channel::Sender<Request<Req, Resp>>,
+}
+
+impl<Req, Resp> Requester<Req, Resp> {
+    /// sends the request and blocks until the response comes back
+    pub fn call(&self, payload: Req) -> Result<Resp, CallError<Req>> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.request_tx
+            .send(Request { payload, reply_tx })
+            .map_err(|send_err| CallError::Disconnected(send_err.into_inner().payload))?;
+        reply_rx.recv().map_err(|NoMoreSenderErr| CallError::NoReply)
+    }
+}
+
+impl<Req, Resp> Clone for Requester<Req, Resp> {
+    fn clone(&self) -> Self {
+        Requester {
+            request_tx: self.request_tx.clone(),
+        }
+    }
+}
+
+/// the server end, handing out the requests one at a time
+pub struct Responder<Req, Resp> {
+    request_rx: tx_rx_channel::Receiver<Request<Req, Resp>>,
+}
+
+impl<Req, Resp> Responder<Req, Resp> {
+    /// blocks for the next request, failing once all the requesters are gone
+    pub fn recv(&self) -> Result<Request<Req, Resp>, NoMoreSenderErr> {
+        self.request_rx.recv()
+    }
+}
+
+/// a request pending a response, where dropping it without responding fails the call with
+/// CallError::NoReply rather than leaving the requester blocked forever
+pub struct Request<Req, Resp> {
+    pub payload: Req,
+    reply_tx: oneshot::Sender<Resp>,
+}
+
+impl<Req, Resp> Request<Req, Resp> {
+    /// a requester that gave up on the call in the meantime makes the response go nowhere, which is
+    /// of no concern to the responder
+    pub fn respond(self, response: Resp) {
+        let _ = self.reply_tx.send(response);
+    }
+}
+
+/// the reasons a call can fail short of a response
+#[derive(Debug, PartialEq, Eq)]
+pub enum CallError<Req> {
+    /// no responder left to take the request, which is handed back
+    Disconnected(Req),
+    /// the request was taken but dropped without a response
+    NoReply,
+}
+
+impl<Req> fmt::Display for CallError<Req> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CallError::Disconnected(_) => f.write_str("calling with no responder left"),
+            CallError::NoReply => f.write_str("request dropped without a response"),
+        }
+    }
+}
+
+impl<Req: fmt::Debug> Error for CallError<Req> {}
+
+pub fn channel<Req: Send + 'static, Resp: Send + 'static>() -> (Requester<Req, Resp>, Responder<Req, Resp>) {
+    let (request_tx, request_rx) = tx_rx_channel::channel();
+    (Requester { request_tx }, Responder { request_rx })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn call_gets_response_from_server_thread() {
+        let (requester, responder) = channel::<u32, u32>();
+        let server = thread::spawn(move || {
+            while let Ok(request) = responder.recv() {
+                let response = request.payload * 2;
+                request.respond(response);
+            }
+        });
+        let clients: Vec<_> = (0..3)
+            .map(|client| {
+                let requester = requester.clone();
+                thread::spawn(move || requester.call(client))
+            })
+            .collect();
+        let responses: Vec<_> = clients.into_iter().map(|client| client.join().unwrap()).collect();
+        assert_eq!(responses, vec![Ok(0), Ok(2), Ok(4)]);
+        drop(requester);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn call_fails_without_server_or_reply() {
+        let (requester, responder) = channel::<u32, u32>();
+        let server = thread::spawn(move || {
+            // drops the request without a response, and then the responder
+            drop(responder.recv());
+        });
+        assert_eq!(requester.call(41), Err(CallError::NoReply));
+        server.join().unwrap();
+        assert_eq!(requester.call(42), Err(CallError::Disconnected(42)));
+    }
+}
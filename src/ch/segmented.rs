@@ -0,0 +1,131 @@
+// the msg queue behind tx_rx_channel, a FIFO queue over a linked list of fixed-size segments, which,
+// unlike a single VecDeque, never has to reallocate and copy over all the msgs it holds when it grows,
+// something that would otherwise happen while the lock of the channel is held. growing takes one
+// more segment, allocated for the most part off the hot path, as the last segment emptied is kept
+// around as a spare for the next time the queue grows
+
+use std::collections::{LinkedList, VecDeque};
+
+pub(crate) struct SegmentedQueue<T> {
+    // every segment is full but the first one, which is drained from the front, and the last one,
+    // which is filled from the back. the odd msg put back at the front goes into the first segment
+    // while it has room, and into a new first segment otherwise
+    segments: LinkedList<VecDeque<T>>,
+    segment_size: usize,
+    spare: Option<VecDeque<T>>,
+    len: usize,
+}
+
+impl<T> SegmentedQueue<T> {
+    pub(crate) fn new(segment_size: usize) -> Self {
+        assert!(segment_size > 0, "segment size must be non-zero");
+        Self {
+            segments: LinkedList::new(),
+            segment_size,
+            spare: None,
+            len: 0,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub(crate) fn front(&self) -> Option<&T> {
+        self.segments.front().and_then(VecDeque::front)
+    }
+
+    pub(crate) fn push_back(&mut self, value: T) {
+        if self.segments.back().is_none_or(|segment| segment.len() == self.segment_size) {
+            let segment = self.spare.take().unwrap_or_else(|| VecDeque::with_capacity(self.segment_size));
+            self.segments.push_back(segment);
+        }
+        self.segments.back_mut().unwrap().push_back(value);
+        self.len += 1;
+    }
+
+    pub(crate) fn push_front(&mut self, value: T) {
+        if self.segments.front().is_none_or(|segment| segment.len() == self.segment_size) {
+            let segment = self.spare.take().unwrap_or_else(|| VecDeque::with_capacity(self.segment_size));
+            self.segments.push_front(segment);
+        }
+        self.segments.front_mut().unwrap().push_front(value);
+        self.len += 1;
+    }
+
+    pub(crate) fn pop_front(&mut self) -> Option<T> {
+        let front_segment = self.segments.front_mut()?;
+        let value = front_segment.pop_front();
+        if front_segment.is_empty() {
+            let emptied = self.segments.pop_front();
+            self.spare = emptied;
+        }
+        self.len -= 1;
+        value
+    }
+
+    pub(crate) fn pop_back(&mut self) -> Option<T> {
+        let back_segment = self.segments.back_mut()?;
+        let value = back_segment.pop_back();
+        if back_segment.is_empty() {
+            let emptied = self.segments.pop_back();
+            self.spare = emptied;
+        }
+        self.len -= 1;
+        value
+    }
+
+    /// takes up to `cnt` values from the front
+    pub(crate) fn drain_front(&mut self, cnt: usize) -> impl Iterator<Item = T> + '_ {
+        (0..cnt).map_while(|_| self.pop_front())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fifo_across_segments() {
+        let mut queue = SegmentedQueue::new(3);
+        (0..10).for_each(|value| queue.push_back(value));
+        assert_eq!((queue.len(), queue.segments.len()), (10, 4));
+        assert_eq!(queue.front(), Some(&0));
+        assert_eq!(queue.drain_front(4).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(queue.pop_back(), Some(9));
+        (10..12).for_each(|value| queue.push_back(value));
+        assert_eq!(queue.drain_front(usize::MAX).collect::<Vec<_>>(), vec![4, 5, 6, 7, 8, 10, 11]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop_front(), None);
+    }
+
+    #[test]
+    fn push_front_before_full_first_segment() {
+        let mut queue = SegmentedQueue::new(2);
+        (0..4).for_each(|value| queue.push_back(value));
+        queue.push_front(-1);
+        assert_eq!((queue.len(), queue.segments.len()), (5, 3));
+        queue.pop_front();
+        queue.pop_front();
+        // room at the front of the first segment again
+        queue.push_front(0);
+        assert_eq!((queue.len(), queue.segments.len()), (4, 2));
+        assert_eq!(std::iter::from_fn(|| queue.pop_front()).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn emptied_segment_kept_as_spare() {
+        let mut queue = SegmentedQueue::new(2);
+        queue.push_back(0);
+        queue.pop_front();
+        assert!(queue.segments.is_empty());
+        assert!(queue.spare.as_ref().is_some_and(|spare| spare.capacity() >= 2));
+        queue.push_back(1);
+        assert!(queue.spare.is_none());
+        assert_eq!(queue.segments.len(), 1);
+    }
+}
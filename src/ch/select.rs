@@ -0,0 +1,188 @@
+// waiting on several receivers of tx_rx_channel at once, for whichever of them has a msg first
+//
+// a blocking select polls the recv_async future of every receiver with one and the same waker, which
+// unparks the selecting thread, s.t. the thread is left in the waker queue of each channel at once
+// and the first send into any of them gets it going again. the futures not picked are dropped on the
+// way out, taking their wakers back out of the channels they were left in
+
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+use super::tx_rx_channel::{NoMoreSenderErr, Receiver, TryRecvError};
+
+/// the set of receivers to select from, each known by the index it got when it was added. a receiver
+/// whose senders are all gone counts as ready, as a recv on it returns right away
+pub struct Select<'a, T> {
+    receivers: Vec<&'a Receiver<T>>,
+    // the receiver to look at first in the next select, moved on by every select s.t. a busy receiver
+    // early on in the set can't keep the others from ever being picked
+    start: Cell<usize>,
+}
+
+impl<'a, T> Select<'a, T> {
+    pub fn new() -> Self {
+        Select {
+            receivers: Vec::new(),
+            start: Cell::new(0),
+        }
+    }
+
+    /// adds a receiver to the set, returning its index
+    pub fn recv(&mut self, receiver: &'a Receiver<T>) -> usize {
+        self.receivers.push(receiver);
+        self.receivers.len() - 1
+    }
+
+    /// blocks until one of the receivers is ready, and returns its index along with what a recv on it
+    /// returned
+    pub fn wait(&self) -> (usize, Result<T, NoMoreSenderErr>) {
+        self.wait_until(None).expect("no deadline to miss")
+    }
+
+    /// wait bounded by a timeout, giving up on the receivers with a SelectTimeoutError once it runs out.
+    /// a timeout too long for an Instant to reach, e.g. Duration::MAX, never runs out
+    pub fn wait_timeout(&self, timeout: Duration) -> Result<(usize, Result<T, NoMoreSenderErr>), SelectTimeoutError> {
+        self.wait_until(Instant::now().checked_add(timeout)).ok_or(SelectTimeoutError)
+    }
+
+    fn wait_until(&self, deadline: Option<Instant>) -> Option<(usize, Result<T, NoMoreSenderErr>)> {
+        assert!(!self.receivers.is_empty(), "no receiver to select from");
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut recv_futures: Vec<_> = self.receivers.iter().map(|receiver| receiver.recv_async()).collect();
+        loop {
+            for idx in self.poll_order() {
+                if let Poll::Ready(result) = Pin::new(&mut recv_futures[idx]).poll(&mut cx) {
+                    return Some((idx, result));
+                }
+            }
+            // park can return spuriously, as well as for an unpark that came in before it, either way
+            // the next round of polls finds out whether there is anything to receive
+            match deadline {
+                None => thread::park(),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return None;
+                    }
+                    thread::park_timeout(deadline - now);
+                },
+            }
+        }
+    }
+
+    /// the counterpart of the default arm of a select, where None is returned right away if none of
+    /// the receivers is ready, instead of blocking
+    pub fn select_with_default(&self) -> Option<(usize, Result<T, NoMoreSenderErr>)> {
+        self.poll_order().find_map(|idx| match self.receivers[idx].try_recv() {
+            Ok(msg) => Some((idx, Ok(msg))),
+            Err(TryRecvError::Disconnected) => Some((idx, Err(NoMoreSenderErr))),
+            Err(TryRecvError::Empty) => None,
+        })
+    }
+
+    fn poll_order(&self) -> impl Iterator<Item = usize> {
+        let receiver_cnt = self.receivers.len();
+        let start = self.start.get();
+        self.start.set((start + 1) % receiver_cnt.max(1));
+        (0..receiver_cnt).map(move |offset| (start + offset) % receiver_cnt)
+    }
+}
+
+impl<'a, T> Default for Select<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// what a wait_timeout comes back with when none of the receivers got ready in time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectTimeoutError;
+
+impl fmt::Display for SelectTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("timed out waiting on the receivers")
+    }
+}
+
+impl Error for SelectTimeoutError {}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tx_rx_channel::channel;
+    use super::*;
+
+    #[test]
+    fn wait_picks_the_rx_sent_into() {
+        let (tx_a, rx_a) = channel::<u32>();
+        let (tx_b, rx_b) = channel::<u32>();
+        let mut select = Select::new();
+        let idx_a = select.recv(&rx_a);
+        let idx_b = select.recv(&rx_b);
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(50));
+                tx_b.send(42).unwrap();
+            });
+            assert_eq!(select.wait(), (idx_b, Ok(42)));
+        });
+        drop(tx_a);
+        assert_eq!(select.wait(), (idx_a, Err(NoMoreSenderErr)));
+    }
+
+    #[test]
+    fn default_when_no_rx_ready() {
+        let (tx_a, rx_a) = channel::<u32>();
+        let (tx_b, rx_b) = channel::<u32>();
+        let mut select = Select::new();
+        select.recv(&rx_a);
+        let idx_b = select.recv(&rx_b);
+        assert_eq!(select.select_with_default(), None);
+        tx_b.send(42).unwrap();
+        assert_eq!(select.select_with_default(), Some((idx_b, Ok(42))));
+        assert_eq!(select.select_with_default(), None);
+    }
+
+    #[test]
+    fn wait_timeout_runs_out_then_picks_rx() {
+        let (tx_a, rx_a) = channel::<u32>();
+        let (tx_b, rx_b) = channel::<u32>();
+        let mut select = Select::new();
+        let idx_a = select.recv(&rx_a);
+        select.recv(&rx_b);
+        let start = Instant::now();
+        assert_eq!(select.wait_timeout(Duration::from_millis(50)), Err(SelectTimeoutError));
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        tx_a.send(42).unwrap();
+        assert_eq!(select.wait_timeout(Duration::from_millis(50)), Ok((idx_a, Ok(42))));
+    }
+
+    #[test]
+    fn wait_timeout_past_any_instant_waits_untimed() {
+        let (tx_a, rx_a) = channel::<u32>();
+        let mut select = Select::new();
+        let idx_a = select.recv(&rx_a);
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(50));
+                tx_a.send(42).unwrap();
+            });
+            assert_eq!(select.wait_timeout(Duration::MAX), Ok((idx_a, Ok(42))));
+        });
+    }
+}
@@ -0,0 +1,216 @@
+// mpmc flavor of the unbounded channel split into shards, one per receiver, each a queue behind a
+// lock of its own, s.t. the receivers don't all contend on one Mutex. a msg goes into the shard picked
+// by the hash of a key, for the msgs of one key to be received in order by one receiver, or else into
+// the next shard round-robin
+//
+// a receiver takes the msgs of its own shard first, and only when that runs dry steals from the other
+// shards, which keeps a slow receiver, or one dropped, from stranding the msgs of its shard. as a
+// sender only wakes up the receiver of the shard it sends into, a receiver blocked on its empty shard
+// looks for msgs to steal again every STEAL_INTERVAL
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+use super::tx_rx_channel::{NoMoreReceiverErr, NoMoreSenderErr, TryRecvError};
+
+const STEAL_INTERVAL: Duration = Duration::from_millis(5);
+
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// sends into the next shard round-robin
+    pub fn send(&self, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        let shard_idx = self.shared.next_shard.fetch_add(1, Ordering::Relaxed) % self.shared.shards.len();
+        self.send_to_shard(shard_idx, value)
+    }
+
+    /// sends into the shard of `key`, where the msgs of any one key are received in the order they
+    /// were sent, but for the ones stolen by a receiver other than the one of the shard
+    pub fn send_keyed<K: Hash + ?Sized>(&self, key: &K, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let shard_idx = (hasher.finish() % self.shared.shards.len() as u64) as usize;
+        self.send_to_shard(shard_idx, value)
+    }
+
+    fn send_to_shard(&self, shard_idx: usize, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        if self.shared.receiver_cnt.load(Ordering::Acquire) == 0 {
+            return Err(NoMoreReceiverErr(value));
+        }
+        let shard = &self.shared.shards[shard_idx];
+        shard.msg_queue.lock().unwrap().push_back(value);
+        shard.msg_flag.notify_one();
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.sender_cnt.fetch_add(1, Ordering::Relaxed);
+        Sender {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        if self.shared.sender_cnt.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // going through the lock of each shard makes sure its receiver is either in the wait by
+            // now, or yet to find out about the senders being gone
+            for shard in &self.shared.shards {
+                drop(shard.msg_queue.lock().unwrap());
+                shard.msg_flag.notify_all();
+            }
+        }
+    }
+}
+
+/// the receiver owning one of the shards, which is not Clone, there being a single receiver per shard
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    shard_idx: usize,
+}
+
+impl<T> Receiver<T> {
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        // the count of senders is looked at before the shards, s.t. a msg sent right before the last
+        // sender is dropped is still found in there
+        let sender_live = self.shared.sender_cnt.load(Ordering::SeqCst) > 0;
+        match self.shared.shards[self.shard_idx].msg_queue.lock().unwrap().pop_front() {
+            Some(msg) => Ok(msg),
+            None => match self.steal() {
+                Some(msg) => Ok(msg),
+                None if sender_live => Err(TryRecvError::Empty),
+                None => Err(TryRecvError::Disconnected),
+            },
+        }
+    }
+
+    pub fn recv(&self) -> Result<T, NoMoreSenderErr> {
+        let shard = &self.shared.shards[self.shard_idx];
+        loop {
+            let sender_live = self.shared.sender_cnt.load(Ordering::SeqCst) > 0;
+            let mut msg_queue_guard = shard.msg_queue.lock().unwrap();
+            if let Some(msg) = msg_queue_guard.pop_front() {
+                return Ok(msg);
+            }
+            drop(msg_queue_guard);
+            if let Some(msg) = self.steal() {
+                return Ok(msg);
+            }
+            if !sender_live {
+                return Err(NoMoreSenderErr);
+            }
+            // a msg may have come into the own shard while stealing, hence the look once more under
+            // the lock to wait with
+            msg_queue_guard = shard.msg_queue.lock().unwrap();
+            if msg_queue_guard.is_empty() && self.shared.sender_cnt.load(Ordering::SeqCst) > 0 {
+                drop(shard.msg_flag.wait_timeout(msg_queue_guard, STEAL_INTERVAL).unwrap());
+            }
+        }
+    }
+
+    /// index of the shard this receiver owns, out of as many as there are receivers
+    pub fn shard(&self) -> usize {
+        self.shard_idx
+    }
+
+    // takes the oldest msg of the first other shard with any, starting from the next shard over, for
+    // the receivers not to all go after the same one
+    fn steal(&self) -> Option<T> {
+        let shard_cnt = self.shared.shards.len();
+        (1..shard_cnt)
+            .map(|offset| &self.shared.shards[(self.shard_idx + offset) % shard_cnt])
+            .find_map(|shard| shard.msg_queue.lock().unwrap().pop_front())
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.receiver_cnt.fetch_sub(1, Ordering::Release);
+    }
+}
+
+struct Shard<T> {
+    msg_queue: Mutex<VecDeque<T>>,
+    // only ever waited on by the receiver of the shard
+    msg_flag: Condvar,
+}
+
+struct Shared<T> {
+    shards: Vec<Shard<T>>,
+    // the shard the next round-robin send goes into, modulo the number of shards
+    next_shard: AtomicUsize,
+    sender_cnt: AtomicUsize,
+    receiver_cnt: AtomicUsize,
+}
+
+/// a channel of `shard_cnt` shards, with as many receivers, the one of each shard at its index
+pub fn channel<T>(shard_cnt: usize) -> (Sender<T>, Vec<Receiver<T>>) {
+    assert!(shard_cnt > 0, "a sharded channel needs at least one shard");
+    let shared = Arc::new(Shared {
+        shards: (0..shard_cnt)
+            .map(|_| Shard {
+                msg_queue: Mutex::new(VecDeque::new()),
+                msg_flag: Condvar::new(),
+            })
+            .collect(),
+        next_shard: AtomicUsize::new(0),
+        sender_cnt: AtomicUsize::new(1),
+        receiver_cnt: AtomicUsize::new(shard_cnt),
+    });
+    let receivers = (0..shard_cnt)
+        .map(|shard_idx| Receiver {
+            shared: Arc::clone(&shared),
+            shard_idx,
+        })
+        .collect();
+    (Sender { shared }, receivers)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn keyed_msgs_in_order_on_own_shard() {
+        let (test_tx, test_rxs) = channel::<u32>(4);
+        for msg in 0..10 {
+            let _ = test_tx.send_keyed("key", msg);
+        }
+        let shard_lens = test_rxs.iter().map(|test_rx| test_tx.shared.shards[test_rx.shard()].msg_queue.lock().unwrap().len());
+        // all the msgs of the key went into one and the same shard
+        assert_eq!(shard_lens.filter(|&len| len > 0).collect::<Vec<_>>(), vec![10]);
+        drop(test_tx);
+        // whichever receiver takes them, own shard or stolen, they come out in order
+        let received: Vec<u32> = std::iter::from_fn(|| test_rxs[0].recv().ok()).collect();
+        assert_eq!(received, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn idle_rx_steals_from_busy_shards() {
+        let (test_tx, mut test_rxs) = channel::<u32>(2);
+        // only the receiver of the second shard is there to receive, with the first one's shard
+        // left to be stolen from
+        let test_rx = test_rxs.pop().unwrap();
+        let mut received = thread::scope(|scope| {
+            let consumer = scope.spawn(|| std::iter::from_fn(|| test_rx.recv().ok()).collect::<Vec<_>>());
+            for msg in 0..100 {
+                let _ = test_tx.send(msg);
+            }
+            drop(test_tx);
+            consumer.join().unwrap()
+        });
+        received.sort();
+        assert_eq!(received, (0..100).collect::<Vec<_>>());
+    }
+}
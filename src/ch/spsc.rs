@@ -0,0 +1,214 @@
+// single-producer single-consumer flavor of the bounded channel over a fixed size ring buffer, which
+// does away with the Mutex and Condvar altogether in favor of two atomic indices
+//
+// the producer is the only party ever writing the tail index and the consumer the only party ever
+// writing the head index, so each side just has to publish its own index with Release ordering and
+// read the other side's with Acquire ordering to see the slots the other side is done with. both
+// indices keep counting up (wrapping around usize), and are mapped onto a slot by modulo capacity
+//
+// the two indices are kept on separate cache lines, as each is written by one thread and read by
+// the other at every step, and sharing one line would have the two cores fight over it on every write
+// of either index, even though they don't touch the same data
+//
+// with no cond var to block on, the blocking send and recv spin (yielding the thread in between)
+// until the ring has room or a msg respectively
+
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use super::cache_padded::CachePadded;
+use super::tx_rx_channel::{NoMoreReceiverErr, NoMoreSenderErr, TryRecvError, TrySendError};
+
+/// the one and only producer, which is not Clone, and whose send methods take `&mut self` s.t. not
+/// even a shared reference to it could let a second thread push concurrently
+pub struct Sender<T> {
+    ring: Arc<Ring<T>>,
+}
+
+impl<T> Sender<T> {
+    pub fn try_send(&mut self, value: T) -> Result<(), TrySendError<T>> {
+        if !self.ring.receiver_live.load(Ordering::Acquire) {
+            return Err(TrySendError::Disconnected(value));
+        }
+        let tail = self.ring.tail.load(Ordering::Relaxed);
+        let head = self.ring.head.load(Ordering::Acquire);
+        if tail.wrapping_sub(head) == self.ring.capacity() {
+            return Err(TrySendError::Full(value));
+        }
+        // SAFETY: the slot at tail is outside of the head..tail range the consumer reads from, and
+        // the Acquire load of head above ensures the consumer is done moving out whatever was in it
+        unsafe {
+            (*self.ring.slot(tail).get()).write(value);
+        }
+        self.ring.tail.store(tail.wrapping_add(1), Ordering::Release);
+        Ok(())
+    }
+
+    pub fn send(&mut self, mut value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        loop {
+            match self.try_send(value) {
+                Ok(()) => return Ok(()),
+                Err(TrySendError::Disconnected(value)) => return Err(NoMoreReceiverErr(value)),
+                Err(TrySendError::Full(unsent)) => {
+                    value = unsent;
+                    thread::yield_now();
+                },
+            }
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.ring.sender_live.store(false, Ordering::Release);
+    }
+}
+
+/// the one and only consumer, see Sender
+pub struct Receiver<T> {
+    ring: Arc<Ring<T>>,
+}
+
+impl<T> Receiver<T> {
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        // the liveness of the sender is looked at before the tail index, s.t. a msg sent right before
+        // the sender is dropped is still seen by the check of the indices below
+        let sender_live = self.ring.sender_live.load(Ordering::Acquire);
+        let head = self.ring.head.load(Ordering::Relaxed);
+        let tail = self.ring.tail.load(Ordering::Acquire);
+        if head == tail {
+            return Err(if sender_live { TryRecvError::Empty } else { TryRecvError::Disconnected });
+        }
+        // SAFETY: the slot at head is within the head..tail range, initialized by the producer before
+        // it published the tail index seen by the Acquire load above, and the head index is only moved
+        // past it after the value is moved out
+        let msg = unsafe { (*self.ring.slot(head).get()).assume_init_read() };
+        self.ring.head.store(head.wrapping_add(1), Ordering::Release);
+        Ok(msg)
+    }
+
+    pub fn recv(&mut self) -> Result<T, NoMoreSenderErr> {
+        loop {
+            match self.try_recv() {
+                Ok(msg) => return Ok(msg),
+                Err(TryRecvError::Disconnected) => return Err(NoMoreSenderErr),
+                Err(TryRecvError::Empty) => thread::yield_now(),
+            }
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.ring.receiver_live.store(false, Ordering::Release);
+    }
+}
+
+struct Ring<T> {
+    buffer: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    // index of the next slot to read, only ever written by the consumer
+    head: CachePadded<AtomicUsize>,
+    // index of the next slot to write, only ever written by the producer
+    tail: CachePadded<AtomicUsize>,
+    sender_live: AtomicBool,
+    receiver_live: AtomicBool,
+}
+
+// SAFETY: the slots are only ever accessed by one side at a time, as handed over by the indices, and
+// the values crossing over from the producer thread to the consumer thread need to be Send
+unsafe impl<T: Send> Send for Ring<T> {}
+unsafe impl<T: Send> Sync for Ring<T> {}
+
+impl<T> Ring<T> {
+    fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn slot(&self, index: usize) -> &UnsafeCell<MaybeUninit<T>> {
+        &self.buffer[index % self.capacity()]
+    }
+}
+
+impl<T> Drop for Ring<T> {
+    fn drop(&mut self) {
+        // both ends are gone by now, leaving the msgs sent but never received to be dropped here
+        let head = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+        let mut index = head;
+        while index != tail {
+            // SAFETY: the head..tail range holds exactly the initialized slots
+            unsafe { (*self.slot(index).get()).assume_init_drop() };
+            index = index.wrapping_add(1);
+        }
+    }
+}
+
+pub fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "capacity of a spsc channel must be non-zero");
+    let ring = Arc::new(Ring {
+        buffer: (0..capacity).map(|_| UnsafeCell::new(MaybeUninit::uninit())).collect(),
+        head: CachePadded(AtomicUsize::new(0)),
+        tail: CachePadded(AtomicUsize::new(0)),
+        sender_live: AtomicBool::new(true),
+        receiver_live: AtomicBool::new(true),
+    });
+
+    (
+        Sender { ring: Arc::clone(&ring) },
+        Receiver { ring },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use super::*;
+
+    #[test]
+    fn spsc_in_order_across_threads() {
+        let (mut test_tx, mut test_rx) = channel::<u32>(4);
+        let producer = thread::spawn(move || {
+            for msg in 0..1000 {
+                let _ = test_tx.send(msg);
+            }
+        });
+        let received: Vec<_> = std::iter::from_fn(|| test_rx.recv().ok()).collect();
+        producer.join().unwrap();
+        assert_eq!(received, (0..1000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn spsc_full_and_disconnected() {
+        let (mut test_tx, mut test_rx) = channel::<u32>(1);
+        assert!(test_tx.try_send(42).is_ok());
+        assert_eq!(test_tx.try_send(43).unwrap_err(), TrySendError::Full(43));
+        assert_eq!(test_rx.try_recv(), Ok(42));
+        assert_eq!(test_rx.try_recv(), Err(TryRecvError::Empty));
+        drop(test_rx);
+        assert_eq!(test_tx.try_send(43).unwrap_err(), TrySendError::Disconnected(43));
+    }
+
+    #[test]
+    fn spsc_drops_unreceived_msgs() {
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+        struct CountDrop;
+        impl Drop for CountDrop {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let (mut test_tx, mut test_rx) = channel::<CountDrop>(4);
+        for _ in 0..3 {
+            let _ = test_tx.try_send(CountDrop);
+        }
+        drop(test_rx.try_recv());
+        drop(test_tx);
+        drop(test_rx);
+        assert_eq!(DROPPED.load(Ordering::SeqCst), 3);
+    }
+}
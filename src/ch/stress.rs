@@ -0,0 +1,167 @@
+// a stress run of any channel implementing ChannelSender and ChannelReceiver, with the knobs of the
+// load set in a StressConfig, for the CI tests as well as ad-hoc experiments with the `stress` feature
+// on. unlike the fifo harness, the run doesn't stop at the first msg out of place but counts what
+// went missing or came twice, s.t. a lossy setup, e.g. a DropOldest overflow policy, gets measured
+// rather than failed
+//
+// the consumers are told to stop by an end marker each, a None sent after all the producers are done,
+// which the channel under test is expected to deliver
+
+use std::collections::HashSet;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::{ChannelReceiver, ChannelSender};
+
+/// what goes through the channel under test, carrying a payload of StressConfig::payload_size bytes
+pub struct StressMsg {
+    producer: usize,
+    seq: u64,
+    payload: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StressConfig {
+    pub producers: usize,
+    pub consumers: usize,
+    /// msgs sent by each producer, unless cut short by `duration`
+    pub messages: u64,
+    pub payload_size: usize,
+    /// time after which the producers stop sending, however many msgs they're still short of
+    pub duration: Option<Duration>,
+}
+
+impl Default for StressConfig {
+    fn default() -> Self {
+        StressConfig {
+            producers: 4,
+            consumers: 1,
+            messages: 10_000,
+            payload_size: 0,
+            duration: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StressReport {
+    /// sends that went through
+    pub sent: u64,
+    /// sends that had the msg handed back
+    pub rejected: u64,
+    /// msgs received, duplicates included
+    pub received: u64,
+    /// msgs sent but never received
+    pub dropped: u64,
+    /// receptions of a msg already received before
+    pub duplicated: u64,
+    pub elapsed: Duration,
+}
+
+impl StressReport {
+    /// msgs received per second
+    pub fn throughput(&self) -> f64 {
+        self.received as f64 / self.elapsed.as_secs_f64()
+    }
+}
+
+impl StressConfig {
+    /// hammers the channel behind `tx` and `rx` with the load of this config, blocking until all the
+    /// producers and the consumers are done
+    pub fn run<Tx, Rx>(&self, tx: &Tx, rx: &Rx) -> StressReport
+    where
+        Tx: ChannelSender<Option<StressMsg>> + Sync,
+        Rx: ChannelReceiver<Option<StressMsg>> + Sync,
+    {
+        assert!(self.producers > 0 && self.consumers > 0, "no producer or no consumer to stress with");
+        let start = Instant::now();
+        let deadline = self.duration.map(|duration| start + duration);
+        let (sent, rejected, received) = thread::scope(|scope| {
+            let consumers: Vec<_> = (0..self.consumers)
+                .map(|_| {
+                    scope.spawn(move || {
+                        std::iter::from_fn(|| rx.recv().flatten()).map(|msg| (msg.producer, msg.seq)).collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            let producers: Vec<_> = (0..self.producers)
+                .map(|producer| {
+                    scope.spawn(move || {
+                        let (mut sent, mut rejected) = (0, 0);
+                        for seq in 0..self.messages {
+                            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                                break;
+                            }
+                            let msg = StressMsg {
+                                producer,
+                                seq,
+                                payload: vec![0; self.payload_size],
+                            };
+                            match tx.send(Some(msg)) {
+                                Ok(()) => sent += 1,
+                                Err(_) => rejected += 1,
+                            }
+                        }
+                        (sent, rejected)
+                    })
+                })
+                .collect();
+            let (sent, rejected) = producers
+                .into_iter()
+                .map(|producer| producer.join().unwrap())
+                .fold((0, 0), |(sent, rejected), (p_sent, p_rejected)| (sent + p_sent, rejected + p_rejected));
+            for _ in 0..self.consumers {
+                let _ = tx.send(None);
+            }
+            let received: Vec<(usize, u64)> = consumers.into_iter().flat_map(|consumer| consumer.join().unwrap()).collect();
+            (sent, rejected, received)
+        });
+        let elapsed = start.elapsed();
+
+        let unique: HashSet<(usize, u64)> = received.iter().copied().collect();
+        StressReport {
+            sent,
+            rejected,
+            received: received.len() as u64,
+            dropped: sent - unique.len() as u64,
+            duplicated: (received.len() - unique.len()) as u64,
+            elapsed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tx_rx_channel;
+    use super::*;
+
+    #[test]
+    fn mpmc_run_neither_drops_nor_duplicates() {
+        let (test_tx, test_rx) = tx_rx_channel::sync_channel(16);
+        let config = StressConfig {
+            consumers: 3,
+            messages: 2_000,
+            payload_size: 64,
+            ..StressConfig::default()
+        };
+        let report = config.run(&test_tx, &test_rx);
+        assert_eq!((report.sent, report.rejected, report.received), (8_000, 0, 8_000));
+        assert_eq!((report.dropped, report.duplicated), (0, 0));
+        assert!(report.throughput() > 0.0);
+    }
+
+    #[test]
+    fn run_cut_short_by_duration() {
+        let (test_tx, test_rx) = tx_rx_channel::channel();
+        let config = StressConfig {
+            producers: 1,
+            messages: u64::MAX,
+            duration: Some(Duration::from_millis(50)),
+            ..StressConfig::default()
+        };
+        let report = config.run(&test_tx, &test_rx);
+        assert!(report.sent > 0 && report.sent < u64::MAX);
+        assert_eq!((report.received, report.dropped), (report.sent, 0));
+        assert!(report.elapsed >= Duration::from_millis(50));
+    }
+}
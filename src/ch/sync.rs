@@ -0,0 +1,15 @@
+// the synchronization primitives behind tx_rx_channel, resolving to the ones of loom when built with
+// `--cfg loom`, for the loom tests to explore the interleavings of the threads contending on them,
+// and to the ones of std otherwise
+//
+// loom's Arc comes without a Weak, which leaves WeakSender out of the loom builds
+
+#[cfg(loom)]
+pub(crate) use loom::sync::{Arc, Condvar, Mutex, MutexGuard};
+#[cfg(loom)]
+pub(crate) use loom::thread;
+
+#[cfg(not(loom))]
+pub(crate) use std::sync::{Arc, Condvar, Mutex, MutexGuard, Weak};
+#[cfg(not(loom))]
+pub(crate) use std::thread;
@@ -0,0 +1,265 @@
+// mpmc flavor of the unbounded channel over the two-lock queue of Michael and Scott, where senders
+// and receivers no longer serialize on one Mutex around the whole queue: the senders take turns on a
+// lock of the tail of a linked list of nodes, the receivers on a lock of its head
+//
+// the list always starts with a dummy node, whose successor holds the next msg to receive, s.t. the
+// head and the tail never point at the same node that both ends would have to update, with an empty
+// queue being the dummy node without a successor. the one field both ends do touch is the next
+// pointer of the last node, when a sender links a node onto it as the receivers look for it, hence
+// an atomic pointer
+//
+// the Condvar for receivers to block on goes with the head lock, which senders stay clear of unless
+// there is a receiver waiting, as announced by a count of waiting receivers
+
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+use super::tx_rx_channel::{NoMoreReceiverErr, NoMoreSenderErr, TryRecvError};
+use super::{ChannelReceiver, ChannelSender};
+
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    pub fn send(&self, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        if self.shared.receiver_cnt.load(Ordering::Acquire) == 0 {
+            return Err(NoMoreReceiverErr(value));
+        }
+        let node = Box::into_raw(Box::new(Node {
+            value: Some(value),
+            next: AtomicPtr::new(ptr::null_mut()),
+        }));
+        let mut tail_guard = self.shared.tail.lock().unwrap();
+        // SAFETY: the tail node is only ever freed by a receiver once it's moved past it as the
+        // head, which requires a successor, whereas the tail node has none until right here
+        unsafe { (**tail_guard).next.store(node, Ordering::SeqCst) };
+        *tail_guard = node;
+        drop(tail_guard);
+
+        // a receiver counts itself as waiting before its last look at the queue, so it's either seen
+        // waiting here or sees the new node in that last look
+        if self.shared.waiting_receiver_cnt.load(Ordering::SeqCst) > 0 {
+            // going through the head lock makes sure the waiting receiver is actually in the wait by
+            // the time of the notification, and not in between its last look and the wait
+            drop(self.shared.head.lock().unwrap());
+            self.shared.msg_flag.notify_one();
+        }
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.sender_cnt.fetch_add(1, Ordering::Relaxed);
+        Sender {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        if self.shared.sender_cnt.fetch_sub(1, Ordering::SeqCst) == 1 {
+            drop(self.shared.head.lock().unwrap());
+            self.shared.msg_flag.notify_all();
+        }
+    }
+}
+
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let sender_live = self.shared.sender_cnt.load(Ordering::SeqCst) > 0;
+        let mut head_guard = self.shared.head.lock().unwrap();
+        match self.shared.dequeue(&mut head_guard) {
+            Some(msg) => Ok(msg),
+            None if sender_live => Err(TryRecvError::Empty),
+            None => Err(TryRecvError::Disconnected),
+        }
+    }
+
+    pub fn recv(&self) -> Result<T, NoMoreSenderErr> {
+        let mut head_guard = self.shared.head.lock().unwrap();
+        loop {
+            if let Some(msg) = self.shared.dequeue(&mut head_guard) {
+                return Ok(msg);
+            }
+            self.shared.waiting_receiver_cnt.fetch_add(1, Ordering::SeqCst);
+            // the last look at the queue, and at the senders, after counting as waiting
+            if let Some(msg) = self.shared.dequeue(&mut head_guard) {
+                self.shared.waiting_receiver_cnt.fetch_sub(1, Ordering::SeqCst);
+                return Ok(msg);
+            }
+            if self.shared.sender_cnt.load(Ordering::SeqCst) == 0 {
+                self.shared.waiting_receiver_cnt.fetch_sub(1, Ordering::SeqCst);
+                return Err(NoMoreSenderErr);
+            }
+            head_guard = self.shared.msg_flag.wait(head_guard).unwrap();
+            self.shared.waiting_receiver_cnt.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.shared.receiver_cnt.fetch_add(1, Ordering::Relaxed);
+        Receiver {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.receiver_cnt.fetch_sub(1, Ordering::Release);
+    }
+}
+
+impl<T> ChannelSender<T> for Sender<T> {
+    fn send(&self, value: T) -> Result<(), T> {
+        Sender::send(self, value).map_err(NoMoreReceiverErr::into_inner)
+    }
+}
+
+impl<T> ChannelReceiver<T> for Receiver<T> {
+    fn recv(&self) -> Option<T> {
+        Receiver::recv(self).ok()
+    }
+}
+
+struct Node<T> {
+    // None for the dummy node at the head
+    value: Option<T>,
+    next: AtomicPtr<Node<T>>,
+}
+
+struct Shared<T> {
+    // the dummy node, whose successor is the next msg to receive
+    head: Mutex<*mut Node<T>>,
+    // the last node, the dummy node itself when the queue is empty
+    tail: Mutex<*mut Node<T>>,
+    // waited on with the head lock
+    msg_flag: Condvar,
+    waiting_receiver_cnt: AtomicUsize,
+    sender_cnt: AtomicUsize,
+    receiver_cnt: AtomicUsize,
+}
+
+// SAFETY: the nodes behind the raw pointers are only ever accessed under the respective lock, but for
+// the atomic next pointer, and the msgs crossing over to the receiving thread need to be Send
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    // to be called with the head lock held, as proven by the guard
+    fn dequeue(&self, head_guard: &mut *mut Node<T>) -> Option<T> {
+        let dummy = *head_guard;
+        // SAFETY: the head node is alive as long as it's the head, which only the holder of the head
+        // lock can change
+        let next = unsafe { (*dummy).next.load(Ordering::SeqCst) };
+        if next.is_null() {
+            return None;
+        }
+        // SAFETY: a successor is linked in by a sender fully initialized, and it's only ever touched by
+        // the holder of the head lock, but for its own next pointer, which is left alone here. taking
+        // its value turns it into the new dummy node
+        let msg = unsafe { (*next).value.take() };
+        *head_guard = next;
+        // SAFETY: with a successor, the old dummy node is no longer the tail, s.t. no sender can get
+        // to it any more, nor any receiver once the head has moved past it
+        drop(unsafe { Box::from_raw(dummy) });
+        msg
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        // both ends are gone by now, leaving the nodes of the msgs never received to be freed here
+        let mut node = *self.head.get_mut().unwrap();
+        while !node.is_null() {
+            // SAFETY: every node from the head onwards is owned by the list
+            let boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next.load(Ordering::Relaxed);
+        }
+    }
+}
+
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let dummy = Box::into_raw(Box::new(Node {
+        value: None,
+        next: AtomicPtr::new(ptr::null_mut()),
+    }));
+    let shared = Arc::new(Shared {
+        head: Mutex::new(dummy),
+        tail: Mutex::new(dummy),
+        msg_flag: Condvar::new(),
+        waiting_receiver_cnt: AtomicUsize::new(0),
+        sender_cnt: AtomicUsize::new(1),
+        receiver_cnt: AtomicUsize::new(1),
+    });
+    (
+        Sender { shared: Arc::clone(&shared) },
+        Receiver { shared },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn mpmc_each_msg_once_in_order_per_tx() {
+        let (test_tx, test_rx) = channel::<(u32, u32)>();
+        let received: Vec<Vec<(u32, u32)>> = thread::scope(|scope| {
+            for producer in 0..3 {
+                let test_tx = test_tx.clone();
+                scope.spawn(move || {
+                    for msg in 0..200 {
+                        let _ = test_tx.send((producer, msg));
+                    }
+                });
+            }
+            drop(test_tx);
+            let consumers: Vec<_> = (0..2)
+                .map(|_| {
+                    let test_rx = test_rx.clone();
+                    scope.spawn(move || std::iter::from_fn(|| test_rx.recv().ok()).collect())
+                })
+                .collect();
+            consumers.into_iter().map(|consumer| consumer.join().unwrap()).collect()
+        });
+
+        let mut all: Vec<(u32, u32)> = received.iter().flatten().copied().collect();
+        all.sort();
+        assert_eq!(all, (0..3).flat_map(|producer| (0..200).map(move |msg| (producer, msg))).collect::<Vec<_>>());
+        // each consumer sees the msgs of any one producer in the order they were sent
+        for consumer_msgs in &received {
+            for producer in 0..3 {
+                let seq: Vec<u32> = consumer_msgs.iter().filter(|(p, _)| *p == producer).map(|(_, m)| *m).collect();
+                assert!(seq.windows(2).all(|pair| pair[0] < pair[1]));
+            }
+        }
+    }
+
+    #[test]
+    fn unreceived_msgs_freed_with_channel() {
+        let (test_tx, test_rx) = channel::<Box<u32>>();
+        let _ = test_tx.send(Box::new(41));
+        let _ = test_tx.send(Box::new(42));
+        assert_eq!(*test_rx.recv().unwrap(), 41);
+        assert_eq!(test_rx.try_recv().map(|msg| *msg), Ok(42));
+        let _ = test_tx.send(Box::new(43));
+        drop(test_tx);
+        assert_eq!(*test_rx.recv().unwrap(), 43);
+        assert_eq!(test_rx.try_recv().unwrap_err(), TryRecvError::Disconnected);
+        let (test_tx, test_rx) = channel::<Box<u32>>();
+        let _ = test_tx.send(Box::new(44));
+    }
+}
@@ -0,0 +1,176 @@
+// watch flavor of the channel, holding a single value that senders overwrite and receivers look at,
+// for propagating some piece of state (a config, a status) where only the latest version matters
+//
+// instead of a queue of msgs, there's one value along with a version number bumped on every send,
+// and each receiver remembers the version it has last seen to tell whether the value has changed since
+
+use std::ops::Deref;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::Condvar;
+
+use super::tx_rx_channel::{NoMoreReceiverErr, NoMoreSenderErr};
+
+pub struct Sender<T> {
+    shared_inner: Arc<SharedInner<T>>,
+}
+
+impl<T> Sender<T> {
+    /// overwrites the current value, waking up every receiver waiting in changed
+    pub fn send(&self, value: T) -> Result<(), NoMoreReceiverErr<T>> {
+        let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+        if shared_mut_data_guard.receiver_cnt == 0 {
+            return Err(NoMoreReceiverErr(value));
+        }
+        shared_mut_data_guard.value = value;
+        shared_mut_data_guard.version += 1;
+        drop(shared_mut_data_guard);
+        self.shared_inner.changed_flag.notify_all();
+        Ok(())
+    }
+
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref { guard: self.shared_inner.inner_mut_data.lock().unwrap() }
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared_inner.inner_mut_data.lock().unwrap().sender_cnt += 1;
+        Sender {
+            shared_inner: Arc::clone(&self.shared_inner)
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut inner_mut_data_lock = self.shared_inner.inner_mut_data.lock().unwrap();
+        inner_mut_data_lock.sender_cnt -= 1;
+        if inner_mut_data_lock.sender_cnt == 0 {
+            drop(inner_mut_data_lock);
+            self.shared_inner.changed_flag.notify_all();
+        }
+    }
+}
+
+pub struct Receiver<T> {
+    shared_inner: Arc<SharedInner<T>>,
+    // the version of the value this receiver has last been told about by changed
+    seen_version: u64,
+}
+
+impl<T> Receiver<T> {
+    /// the current value, holding the lock of the channel for as long as the returned Ref is alive,
+    /// so it's better not kept around, as senders are blocked in the meantime
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref { guard: self.shared_inner.inner_mut_data.lock().unwrap() }
+    }
+
+    /// blocks until a version newer than the one last seen by this receiver is sent, returning
+    /// right away if it has already been. errs once all the senders are gone, as there won't be any
+    /// new version any more
+    pub fn changed(&mut self) -> Result<(), NoMoreSenderErr> {
+        let mut shared_mut_data_guard = self.shared_inner.inner_mut_data.lock().unwrap();
+        loop {
+            if shared_mut_data_guard.version > self.seen_version {
+                self.seen_version = shared_mut_data_guard.version;
+                return Ok(());
+            }
+            if shared_mut_data_guard.sender_cnt == 0 {
+                return Err(NoMoreSenderErr);
+            }
+            shared_mut_data_guard = self.shared_inner.changed_flag.wait(shared_mut_data_guard).unwrap();
+        }
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.shared_inner.inner_mut_data.lock().unwrap().receiver_cnt += 1;
+        Receiver {
+            shared_inner: Arc::clone(&self.shared_inner),
+            seen_version: self.seen_version,
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared_inner.inner_mut_data.lock().unwrap().receiver_cnt -= 1;
+    }
+}
+
+/// read access to the value of the channel, backed by the guard of the lock of the channel
+pub struct Ref<'a, T> {
+    guard: MutexGuard<'a, SharedInnerMut<T>>,
+}
+
+impl<'a, T> Deref for Ref<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard.value
+    }
+}
+
+struct SharedInner<T> {
+    inner_mut_data: Mutex<SharedInnerMut<T>>,
+    changed_flag: Condvar,
+}
+
+struct SharedInnerMut<T> {
+    value: T,
+    // starting at 0 for the initial value, which receivers count as already seen
+    version: u64,
+    sender_cnt: usize,
+    receiver_cnt: usize,
+}
+
+pub fn channel<T>(initial: T) -> (Sender<T>, Receiver<T>) {
+    let new_shared_inner = Arc::new(SharedInner {
+        inner_mut_data: Mutex::new(SharedInnerMut {
+            value: initial,
+            version: 0,
+            sender_cnt: 1,
+            receiver_cnt: 1,
+        }),
+        changed_flag: Condvar::new(),
+    });
+
+    (
+        Sender { shared_inner: Arc::clone(&new_shared_inner) },
+        Receiver { shared_inner: Arc::clone(&new_shared_inner), seen_version: 0 },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn rx_sees_latest_value() {
+        let (test_tx, test_rx) = channel::<u32>(0);
+        assert_eq!(*test_rx.borrow(), 0);
+        let _ = test_tx.send(1);
+        let _ = test_tx.send(2);
+        // intermediate versions are simply overwritten
+        assert_eq!(*test_rx.borrow(), 2);
+    }
+
+    #[test]
+    fn rx_changed_blocks_until_new_version() {
+        let (test_tx, mut test_rx) = channel::<&str>("initial");
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            let _ = test_tx.send("updated");
+        });
+        assert!(test_rx.changed().is_ok());
+        assert_eq!(*test_rx.borrow(), "updated");
+        // no newer version is ever sent before the sender is dropped
+        assert!(test_rx.changed().is_err());
+    }
+}
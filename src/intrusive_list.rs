@@ -0,0 +1,300 @@
+use std::cell::Cell;
+use std::fmt;
+use std::num::NonZeroU64;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// the source of the ids the lists stamp into the links of their values, unique for the process
+static NEXT_LIST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// a doubly linked list where the links are not in nodes the list allocates around the values, but
+/// in the values themselves, a Link field of the user's struct per list the struct can be a member
+/// of, the way the kernel of an OS keeps e.g. a task in the run queue and in the list of all tasks at
+/// once, with no allocation to add or remove it, and the removal of a task it already has a reference
+/// to in O(1) from wherever it is in the list
+///
+/// the list owns nothing, only borrows the values linked into it for 'a, which keeps them in place
+/// and alive for as long as the list may point to them, with the links behind Cells to be updated
+/// through the shared references. hence no unsafe code here, where the usual intrusive lists of C, or
+/// of the crates built on raw pointers, leave all of that to the caller
+pub struct List<'a, A: Adapter<'a>> {
+    id: NonZeroU64,
+    front: Option<&'a A::Item>,
+    back: Option<&'a A::Item>,
+    len: usize,
+}
+
+/// the hooks of one list in a value, to be embedded as a field of its struct
+pub struct Link<'a, T> {
+    prev: Cell<Option<&'a T>>,
+    next: Cell<Option<&'a T>>,
+    // the id of the list the value is in by this link, if any
+    owner: Cell<Option<NonZeroU64>>,
+}
+
+impl<'a, T> Link<'a, T> {
+    pub const fn new() -> Self {
+        Link { prev: Cell::new(None), next: Cell::new(None), owner: Cell::new(None) }
+    }
+
+    /// whether the value is in a list by this link right now
+    pub fn is_linked(&self) -> bool {
+        self.owner.get().is_some()
+    }
+}
+
+impl<T> Default for Link<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for Link<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Link").field("linked", &self.is_linked()).finish()
+    }
+}
+
+/// the accessor from a value to the one of its links a kind of list goes by, implemented by a marker
+/// type per Link field, s.t. a struct with two links can be in a list of each kind
+pub trait Adapter<'a> {
+    type Item: 'a;
+
+    fn link(item: &'a Self::Item) -> &'a Link<'a, Self::Item>;
+}
+
+impl<'a, A: Adapter<'a>> List<'a, A> {
+    pub fn new() -> Self {
+        let id = NEXT_LIST_ID.fetch_add(1, Ordering::Relaxed);
+        List { id: NonZeroU64::new(id).expect("list ids ran out"), front: None, back: None, len: 0 }
+    }
+
+    /// # Panics
+    ///
+    /// if the value is in a list by the same link already
+    pub fn push_front(&mut self, item: &'a A::Item) {
+        let link = self.link_unlinked(item);
+        link.next.set(self.front);
+        match self.front {
+            Some(old_front) => A::link(old_front).prev.set(Some(item)),
+            None => self.back = Some(item),
+        }
+        self.front = Some(item);
+        self.len += 1;
+    }
+
+    /// # Panics
+    ///
+    /// if the value is in a list by the same link already
+    pub fn push_back(&mut self, item: &'a A::Item) {
+        let link = self.link_unlinked(item);
+        link.prev.set(self.back);
+        match self.back {
+            Some(old_back) => A::link(old_back).next.set(Some(item)),
+            None => self.front = Some(item),
+        }
+        self.back = Some(item);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<&'a A::Item> {
+        let front = self.front?;
+        self.unlink(front);
+        Some(front)
+    }
+
+    pub fn pop_back(&mut self) -> Option<&'a A::Item> {
+        let back = self.back?;
+        self.unlink(back);
+        Some(back)
+    }
+
+    /// takes the value out of the list in O(1), by its own links to its neighbours, and returns
+    /// whether it was in this list at all. a value linked into another list of the same kind is left
+    /// be, told apart by the id of its list the link keeps
+    pub fn remove(&mut self, item: &'a A::Item) -> bool {
+        if A::link(item).owner.get() != Some(self.id) {
+            return false;
+        }
+        self.unlink(item);
+        true
+    }
+
+    pub fn front(&self) -> Option<&'a A::Item> {
+        self.front
+    }
+
+    pub fn back(&self) -> Option<&'a A::Item> {
+        self.back
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> Iter<'a, A> {
+        Iter { next_item: self.front }
+    }
+
+    fn link_unlinked(&self, item: &'a A::Item) -> &'a Link<'a, A::Item> {
+        let link = A::link(item);
+        assert!(link.owner.replace(Some(self.id)).is_none(), "the value is in a list by this link already");
+        link
+    }
+
+    fn unlink(&mut self, item: &'a A::Item) {
+        let link = A::link(item);
+        let (prev, next) = (link.prev.take(), link.next.take());
+        match prev {
+            Some(prev) => A::link(prev).next.set(next),
+            None => self.front = next,
+        }
+        match next {
+            Some(next) => A::link(next).prev.set(prev),
+            None => self.back = prev,
+        }
+        link.owner.set(None);
+        self.len -= 1;
+    }
+}
+
+impl<'a, A: Adapter<'a>> Default for List<'a, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, A: Adapter<'a>> Drop for List<'a, A> {
+    fn drop(&mut self) {
+        // the values outlive the list, and are left unlinked, for them to join another list later
+        while self.pop_front().is_some() {}
+    }
+}
+
+/// backing impl for iter, following the next links from the front on
+pub struct Iter<'a, A: Adapter<'a>> {
+    next_item: Option<&'a A::Item>,
+}
+
+impl<'a, A: Adapter<'a>> Iterator for Iter<'a, A> {
+    type Item = &'a A::Item;
+
+    fn next(&mut self) -> Option<&'a A::Item> {
+        let item = self.next_item?;
+        self.next_item = A::link(item).next.get();
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Task<'a> {
+        id: u32,
+        all_link: Link<'a, Task<'a>>,
+        ready_link: Link<'a, Task<'a>>,
+    }
+
+    impl Task<'_> {
+        fn new(id: u32) -> Self {
+            Task { id, all_link: Link::new(), ready_link: Link::new() }
+        }
+    }
+
+    struct AllTasks;
+
+    impl<'a> Adapter<'a> for AllTasks {
+        type Item = Task<'a>;
+
+        fn link(item: &'a Task<'a>) -> &'a Link<'a, Task<'a>> {
+            &item.all_link
+        }
+    }
+
+    struct ReadyTasks;
+
+    impl<'a> Adapter<'a> for ReadyTasks {
+        type Item = Task<'a>;
+
+        fn link(item: &'a Task<'a>) -> &'a Link<'a, Task<'a>> {
+            &item.ready_link
+        }
+    }
+
+    fn ids<'a, A: Adapter<'a, Item = Task<'a>>>(list: &List<'a, A>) -> Vec<u32> {
+        list.iter().map(|task| task.id).collect()
+    }
+
+    #[test]
+    fn values_in_two_lists_at_once() {
+        let tasks = [Task::new(1), Task::new(2), Task::new(3)];
+        let mut all = List::<AllTasks>::new();
+        let mut ready = List::<ReadyTasks>::new();
+        tasks.iter().for_each(|task| all.push_back(task));
+        ready.push_front(&tasks[2]);
+        ready.push_front(&tasks[0]);
+        assert_eq!((ids(&all), ids(&ready)), (vec![1, 2, 3], vec![1, 3]));
+        assert!(tasks[1].all_link.is_linked() && !tasks[1].ready_link.is_linked());
+
+        // taken out of the middle of one list by the reference alone, and left in the other one
+        assert!(all.remove(&tasks[1]));
+        assert!(!all.remove(&tasks[1]));
+        assert!(ready.remove(&tasks[2]));
+        assert_eq!((ids(&all), ids(&ready)), (vec![1, 3], vec![1]));
+        assert_eq!(all.back().map(|task| task.id), Some(3));
+
+        assert_eq!(ready.pop_back().map(|task| task.id), Some(1));
+        assert!(ready.is_empty() && ready.front().is_none());
+        all.push_front(&tasks[1]);
+        assert_eq!(all.pop_front().map(|task| task.id), Some(2));
+        assert_eq!((all.len(), all.front().map(|task| task.id)), (2, Some(1)));
+        drop(all);
+        // the list dropped, the values are unlinked, to be pushed into another one
+        assert!(tasks.iter().all(|task| !task.all_link.is_linked()));
+        let mut again = List::<AllTasks>::new();
+        again.push_back(&tasks[0]);
+        assert_eq!(ids(&again), vec![1]);
+    }
+
+    #[test]
+    fn remove_from_empty_list_leaves_other_list_be() {
+        let tasks = [Task::new(1), Task::new(2)];
+        let mut all = List::<AllTasks>::new();
+        let mut empty = List::<AllTasks>::new();
+        tasks.iter().for_each(|task| all.push_back(task));
+        assert!(!empty.remove(&tasks[0]));
+        assert!(empty.is_empty() && empty.front().is_none());
+        assert!(tasks[0].all_link.is_linked());
+        assert_eq!((ids(&all), all.len()), (vec![1, 2], 2));
+    }
+
+    #[test]
+    fn remove_through_wrong_list_leaves_both_be() {
+        let tasks = [Task::new(1), Task::new(2), Task::new(3)];
+        let mut first = List::<AllTasks>::new();
+        let mut second = List::<AllTasks>::new();
+        first.push_back(&tasks[0]);
+        first.push_back(&tasks[1]);
+        second.push_back(&tasks[2]);
+        assert!(!second.remove(&tasks[1]));
+        assert!(!first.remove(&tasks[2]));
+        assert_eq!((ids(&first), first.len()), (vec![1, 2], 2));
+        assert_eq!((ids(&second), second.len()), (vec![3], 1));
+        assert!(first.remove(&tasks[1]) && second.remove(&tasks[2]));
+        assert!(second.is_empty() && ids(&first) == vec![1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "in a list by this link already")]
+    fn push_linked_value_panics() {
+        let task = Task::new(1);
+        let mut first = List::<AllTasks>::new();
+        let mut second = List::<AllTasks>::new();
+        first.push_back(&task);
+        second.push_back(&task);
+    }
+}
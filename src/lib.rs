@@ -1,3 +1,10 @@
-mod ch;
-mod mut_single_linked_list;
-mod proptest;
\ No newline at end of file
+pub mod arena_linked_list;
+pub mod ch;
+pub mod intrusive_list;
+pub mod mut_single_linked_list;
+pub mod persistent_list;
+pub mod rc_double_linked_list;
+pub mod unsafe_double_linked_list;
+#[cfg(test)]
+mod list_miri_tests;
+mod proptest;
@@ -0,0 +1,124 @@
+// the tests of the lists built on raw pointers, mut_single_linked_list and unsafe_double_linked_list,
+// that are about the pointers rather than the values: every reference handed out kept alive and
+// written through while the list goes on linking and unlinking nodes around it, nodes moving between
+// lists, and each value dropped once, in order. the values being right says little of that, as UB may
+// well compute the right values on the day, hence the tests are meant for miri, small enough to run
+// under it in seconds, and to be run under both of its aliasing models:
+//
+//     cargo +nightly miri test --lib list_miri_tests
+//     MIRIFLAGS=-Zmiri-tree-borrows cargo +nightly miri test --lib list_miri_tests
+
+use std::cell::RefCell;
+
+use crate::mut_single_linked_list::LinkedList;
+use crate::unsafe_double_linked_list::LinkedList as Deque;
+
+// a value recording its drop into the log it shares with the others
+struct DropLogged<'a>(u32, &'a RefCell<Vec<u32>>);
+
+impl Drop for DropLogged<'_> {
+    fn drop(&mut self) {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+#[test]
+fn refs_from_iter_mut_written_out_of_order() {
+    let mut list: LinkedList<u32> = (0..5).collect();
+    let refs: Vec<&mut u32> = list.iter_mut().collect();
+    // the last node is written first, through the &mut handed out last, while the ones to the nodes
+    // linking to it are alive
+    for (value, offset) in refs.into_iter().rev().zip(10..) {
+        *value += offset;
+    }
+    // a write through the tail pointer right after, to the node the iterator went through last
+    *list.back_mut().unwrap() += 100;
+    assert_eq!(Vec::from(list), vec![14, 14, 14, 14, 114]);
+
+    let mut deque: Deque<u32> = Deque::new();
+    (0..5).for_each(|value| deque.push_back(value));
+    let mut iter_mut = deque.iter_mut();
+    let (front, back) = (iter_mut.next().unwrap(), iter_mut.next_back().unwrap());
+    let middle: Vec<&mut u32> = iter_mut.rev().collect();
+    *back += 10;
+    middle.into_iter().for_each(|value| *value *= 2);
+    *front += 10;
+    deque.push_front(9);
+    deque.push_back(9);
+    assert_eq!(deque.into_iter().collect::<Vec<_>>(), vec![9, 10, 2, 4, 6, 14, 9]);
+}
+
+#[test]
+fn cursor_edits_around_written_values() {
+    let mut list: LinkedList<u32> = (0..4).collect();
+    for value in list.iter_mut() {
+        *value *= 10;
+    }
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    // the current value written, then the nodes on either side of it relinked, then written again
+    *cursor.current().unwrap() += 1;
+    cursor.insert_before(5);
+    cursor.insert_after(15);
+    *cursor.current().unwrap() += 1;
+    *cursor.peek_next().unwrap() += 1;
+    assert_eq!(cursor.remove_current(), Some(12));
+    cursor.move_next();
+    cursor.move_next();
+    // the back node removed, and the ghost position written through the new tail
+    assert_eq!(cursor.remove_current(), Some(30));
+    cursor.insert_before(40);
+    assert_eq!(list.back_mut().map(|value| *value), Some(40));
+    for value in list.iter_mut() {
+        *value += 1;
+    }
+    assert_eq!(Vec::from(list), vec![1, 6, 17, 21, 41]);
+}
+
+#[test]
+fn nodes_moved_between_lists() {
+    let mut list: LinkedList<u32> = (0..6).collect();
+    let mut suffix = list.split_off(3);
+    // both halves written at the ends they were split at
+    *list.back_mut().unwrap() += 10;
+    *suffix.front_mut().unwrap() += 10;
+    suffix.push_back(6);
+    list.push_back(7);
+    list.append(&mut suffix);
+    assert!(suffix.is_empty());
+    // the nodes of the suffix written again, now through the links of the list they're moved into
+    for value in list.iter_mut().skip(4) {
+        *value *= 2;
+    }
+    list.reverse();
+    list.sort();
+    let tail = list.split_off(list.len() - 1);
+    list.retain(|value| value % 2 == 0);
+    assert_eq!((Vec::from(list), Vec::from(tail)), (vec![0, 8, 10, 12, 12], vec![26]));
+}
+
+#[test]
+fn values_dropped_once_in_order() {
+    let log = RefCell::new(vec![]);
+    let mut list: LinkedList<DropLogged> = (0..8).map(|id| DropLogged(id, &log)).collect();
+    drop(list.split_off(6));
+    assert_eq!(*log.borrow(), vec![6, 7]);
+    // the values extracted are dropped by the caller, the ones reached but kept aren't dropped at all
+    drop(list.extract_if(|value| value.0 % 2 == 1).next());
+    assert_eq!(*log.borrow(), vec![6, 7, 1]);
+    let suffix = list.split_off(3);
+    drop(list.remove(0));
+    drop(suffix);
+    drop(list);
+    // the list drops its values from the head on
+    assert_eq!(*log.borrow(), vec![6, 7, 1, 0, 4, 5, 2, 3]);
+
+    log.borrow_mut().clear();
+    let mut deque = Deque::new();
+    (0..4).for_each(|id| deque.push_back(DropLogged(id, &log)));
+    drop(deque.pop_back());
+    let mut into_iter = deque.into_iter();
+    drop(into_iter.next());
+    drop(into_iter);
+    assert_eq!(*log.borrow(), vec![3, 0, 1, 2]);
+}
@@ -1,6 +1,12 @@
-#![allow(dead_code, unused)]
+#![allow(dead_code, unused, clippy::needless_return, clippy::mem_replace_option_with_none)]
 
-use std::{marker::PhantomData, mem};
+// the list needs nothing of std but the heap allocation of its nodes, hence core and alloc alone,
+// for the module to build in a #![no_std] crate with an allocator, as in embedded setups, its tests
+// being the one part left to std
+extern crate alloc;
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{cmp::Ordering, fmt, hash::{Hash, Hasher}, marker::PhantomData, mem, ops::{Index, IndexMut}, ptr::NonNull};
 
 /// a workable declaration of linked list from the functional programming perspective (copied from Scala) that 
 /// requires only the addition of Box in the non-empty list variant to make sure the type is sized to compile in Rust
@@ -14,13 +20,69 @@ pub enum LinkedListBadLayout {
     Cons(i32, Box<LinkedListBadLayout>)
 }
 
+/// the stack API of LinkedList, push_front, pop_front and peek, on the bad layout, for the two to be
+/// compared doing the same work. the first value lives in the enum itself, wherever the list is,
+/// while all the others live on the heap, s.t. pushing a value moves the one that was first from the
+/// stack into a new Box, and popping it moves the next one out of its Box back onto the stack, where
+/// LinkedList only ever relinks the Boxes its values were put in once
+impl LinkedListBadLayout {
+    pub fn new() -> Self {
+        LinkedListBadLayout::Nil
+    }
+
+    pub fn push_front(&mut self, value: i32) {
+        let old_list = mem::replace(self, LinkedListBadLayout::Nil);
+        *self = LinkedListBadLayout::Cons(value, Box::new(old_list));
+    }
+
+    pub fn pop_front(&mut self) -> Option<i32> {
+        match mem::replace(self, LinkedListBadLayout::Nil) {
+            LinkedListBadLayout::Nil => None,
+            LinkedListBadLayout::Cons(value, next) => {
+                *self = *next;
+                Some(value)
+            },
+        }
+    }
+
+    pub fn peek(&self) -> Option<&i32> {
+        match self {
+            LinkedListBadLayout::Nil => None,
+            LinkedListBadLayout::Cons(value, _) => Some(value),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self, LinkedListBadLayout::Nil)
+    }
+}
+
+impl Default for LinkedListBadLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Such design of the entities of in a linked list would lead to a efficient memory layout ?!
 pub struct LinkedList<T> {
     /// this type models a handle to the linked list, as convention, by accessing it from the head
     // head: LinkInvented<T>,
-    head: Link<T>
+    head: Link<T>,
+    /// the last node, for the operations at the back of the list to be O(1) rather than a walk from
+    /// the head, None for an empty list
+    tail: Link<T>,
+    /// the number of nodes, kept up to date by every method adding or removing one, for the size of
+    /// the list to be known without a walk over it
+    len: usize,
+    /// the list owns its nodes, and drops them along with their data, as it would through Boxes
+    owns_nodes: PhantomData<Box<Node<T>>>,
 }
 
+// the raw pointers opt the list out of Send and Sync, which the list owning all of its nodes deserves
+// as much as a Vec<T> would
+unsafe impl<T: Send> Send for LinkedList<T> {}
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
 // enum LinkInvented<T> {
 //     /// this type models a pointer to the next item in the linked list that is aware of the two possible types of the item
 //     /// i.e. Nil or a non-empty item
@@ -30,7 +92,13 @@ pub struct LinkedList<T> {
 //     NonEmpty(Box<Node<T>>),
 // }
 
-type Link<T> = Option<Box<Node<T>>>;
+/// the links used to be Option<Box<Node<T>>>, until the tail pointer came along: a Box asserts that
+/// it's the one and only pointer to its node, which a second pointer to the last node breaks, and
+/// miri reports as UB as soon as the node is written through the one and then read through the other.
+/// so the nodes are allocated as Boxes, but owned through the raw pointers they're turned into by
+/// Box::into_raw, and only turned back into Boxes by Box::from_raw to be popped off the list. NonNull
+/// is never null, so the None variant still fits into the niche of the null pointer
+type Link<T> = Option<NonNull<Node<T>>>;
 // enum Link {
 //     None,
 //     Some(Box<Node>),
@@ -47,98 +115,624 @@ struct Node<T> {
 /// 
 impl<T> LinkedList<T> {
 
+    pub fn new() -> Self {
+        LinkedList { head: None, tail: None, len: 0, owns_nodes: PhantomData }
+    }
+
     pub fn peek(&self) -> Option<&T> {
-        match self.head {
-            None => {
-                return None;
-            },
-            Some(ref ref_to_boxed_node) => {
-                // the mechanics of automatic dereference of Rust enables that, having
-                // a &Box<Node<T>> at hand implies having &Node<T>, which gives the &T
-                // required for the implementation of the API
-                return Some(&ref_to_boxed_node.data);
+        // SAFETY: every link of the list points to a live node owned by the list, which is borrowed
+        // for as long as the returned reference lives
+        self.head.map(|head| unsafe { &(*head.as_ptr()).data })
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: see peek, with the list borrowed mutably
+        self.head.map(|head| unsafe { &mut (*head.as_ptr()).data })
+    }
+
+    /// the same as peek_mut, by the name the other end of the list goes by in back_mut
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.peek_mut()
+    }
+
+    /// implementation that would create a new node with the given input data and the pointer re-using the
+    /// existing link of the current head, and update the current head be the link to this new node
+    pub fn push_front(&mut self, value: T) {
+        let new_node = Self::alloc_node(value, self.head);
+        self.head = Some(new_node);
+        // the first node into an empty list is its last one as well
+        if self.tail.is_none() {
+            self.tail = Some(new_node);
+        }
+        self.len += 1;
+    }
+
+    /// adds the value at the back of the list, in O(1) by the pointer to the last node
+    pub fn push_back(&mut self, value: T) {
+        let new_node = Self::alloc_node(value, None);
+        match self.tail {
+            None => self.head = Some(new_node),
+            // SAFETY: see peek, with the list borrowed mutably
+            Some(tail) => unsafe { (*tail.as_ptr()).next = Some(new_node) },
+        }
+        self.tail = Some(new_node);
+        self.len += 1;
+    }
+
+    /// moves all the nodes of the other list onto the back of this one, in O(1) by the pointer to the
+    /// last node, leaving the other list empty, with no node allocated nor value moved
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        let other = mem::take(other);
+        match self.tail {
+            None => self.head = other.head,
+            // SAFETY: see peek, with both lists borrowed mutably, where the nodes of the other list are
+            // taken over by this one, the other one being left empty
+            Some(tail) => unsafe { (*tail.as_ptr()).next = other.head },
+        }
+        if other.tail.is_some() {
+            self.tail = other.tail;
+        }
+        self.len += other.len;
+        // the emptied husk of the other list owns no node anymore, which its drop would otherwise free
+        mem::forget(other);
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: see peek
+        self.tail.map(|tail| unsafe { &(*tail.as_ptr()).data })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: see peek_mut
+        self.tail.map(|tail| unsafe { &mut (*tail.as_ptr()).data })
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        /// implementation trick is to turn the raw pointer of the head back into the Box it was
+        /// allocated as, which hands the ownership of the node over to the local variable s.t. the
+        /// node's data and link to the (originally) second next can be moved out of it
+        let head = self.head?;
+        // SAFETY: the node was allocated as a Box by alloc_node, and is unlinked from the list right
+        // below, leaving no other pointer to it
+        let boxed_node_to_pop = unsafe { Box::from_raw(head.as_ptr()) };
+        self.head = boxed_node_to_pop.next;
+        if self.head.is_none() {
+            self.tail = None;
+        }
+        self.len -= 1;
+        // the binding of the boxed node to the local var conviently
+        // implies that after we're done with the business of accessing
+        // stuff out of the boxed node, the box is also correctly deallocated
+        // by the virtue of its owning variable going out of scope
+        return Some(boxed_node_to_pop.data);
+    }
+
+    /// removes the value at the back of the list, which takes a walk from the head to find the node
+    /// before the last one, in O(n), a singly linked list having no link back from the last node
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        if self.head == Some(tail) {
+            return self.pop_front();
+        }
+        let mut before_tail = self.head.unwrap();
+        // SAFETY: see peek, where the walk ends on the node before the last one, there being at
+        // least two nodes
+        unsafe {
+            while (*before_tail.as_ptr()).next != Some(tail) {
+                before_tail = (*before_tail.as_ptr()).next.unwrap();
             }
+            (*before_tail.as_ptr()).next = None;
         }
+        self.tail = Some(before_tail);
+        self.len -= 1;
+        // SAFETY: see pop_front
+        Some(unsafe { Box::from_raw(tail.as_ptr()) }.data)
+    }
+
+    /// moves the first n values to the back, in the order they were in, such that the value at index n
+    /// becomes the first one, which relinks the nodes at the two ends and the split in O(n), with no
+    /// value moved, e.g. to hand the turn over to the next one in a round robin
+    ///
+    /// # Panics
+    ///
+    /// if n is greater than the len, like VecDeque::rotate_left
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.len, "cannot rotate by {n}, more than the len {}", self.len);
+        let mut rotated = self.split_off(n);
+        rotated.append(self);
+        *self = rotated;
     }
 
-    /// implementation that would create a new boxed node with the given input data and the pointer re-using the
-    /// existing link of the current head, and update the current head be the link to this new boxed node
-    pub fn append(&mut self, value: T) {
-        /// implementation trick is to obtain the link of the current head by replacing it out with a temporary None Link
-        /// to complete the creation of the new boxed node
-        let new_boxed_node = Box::new(
-            Node {
-                data: value,
-                next: mem::replace(&mut self.head, None)
+    /// moves the last n values to the front, in the order they were in, which takes a walk to the
+    /// node n from the back, in O(len - n)
+    ///
+    /// # Panics
+    ///
+    /// if n is greater than the len, like VecDeque::rotate_right
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(n <= self.len, "cannot rotate by {n}, more than the len {}", self.len);
+        self.rotate_left(self.len - n);
+    }
+
+    /// reverses the order of the values by turning the link of every node around to the node before
+    /// it, in O(n) with nothing allocated nor moved, where the head and the tail trade places
+    pub fn reverse(&mut self) {
+        let mut before: Link<T> = None;
+        let mut current = self.head;
+        while let Some(node) = current {
+            // SAFETY: see peek, with the list borrowed mutably, where each node is visited once
+            unsafe {
+                current = (*node.as_ptr()).next;
+                (*node.as_ptr()).next = before;
             }
-        );
+            before = Some(node);
+        }
+        mem::swap(&mut self.head, &mut self.tail);
+    }
 
-        self.head = Some(new_boxed_node)
+    /// keeps only the values the predicate holds for, unlinking and dropping the others in one walk
+    /// over the list, in order
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        self.retain_mut(|value| keep(value));
     }
-    
-    pub fn pop_front(&mut self) -> Option<T> {
-        /// implementation trick, similar to that in append API, is to replacing the Link
-        /// out of the head field gain ownership into the boxed node by binding it with a 
-        /// local variable s.t. the node's data and link to the (originally) second next
-        /// can be accessed
-        match mem::replace(&mut self.head, None) {
-            None => {
-                return None;
-            },
-            Some(boxed_node_to_pop) => {
-                
-                // do what needs to be done for the API implementation with the boxed node
-                self.head = boxed_node_to_pop.next;
-                return Some(boxed_node_to_pop.data);
-
-            // the binding of the boxed node to the local var conviently
-            // implies that after we're done with the business of accessing
-            // stuff out of the boxed node, the box is also correctly deallocated
-            // by the virtue of its owning variable going out of scope
+
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut keep: F) {
+        let mut cursor = self.cursor_front_mut();
+        while let Some(value) = cursor.current() {
+            if keep(value) {
+                cursor.move_next();
+            } else {
+                cursor.remove_current();
             }
         }
     }
 
+    /// an iterator taking the values the predicate holds for out of the list as it goes, leaving the
+    /// others in place, as well as the values not reached yet once the iterator is dropped, like the
+    /// LinkedList::extract_if of nightly std
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, extract: F) -> ExtractIf<'_, T, F> {
+        ExtractIf { cursor: self.cursor_front_mut(), extract }
+    }
+
+    /// sorts the values in place with a merge sort, stable, in O(n log n), which relinks the nodes
+    /// rather than moving any value, unlike sorting a Vec the values are collected into
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        if self.len < 2 {
+            return;
+        }
+        // the nodes are handed over to the guard while they're being relinked, and back to the list
+        // once sorted, or on a panic of compare midway, in whatever order they're left in by then
+        let mut nodes = RelinkOnDrop::new(self);
+        // SAFETY: the guard holds all of the nodes of the list, which it borrows mutably
+        unsafe { nodes.sort(&mut compare) };
+    }
+
+    /// the values of both lists, sorted already, in one sorted list, made of the nodes of the two
+    /// relinked one after another in O(n + m), with nothing allocated. of equal values, the ones of
+    /// this list go first
+    pub fn merge<F: FnMut(&T, &T) -> Ordering>(mut self, mut other: LinkedList<T>, mut compare: F) -> LinkedList<T> {
+        if self.is_empty() || other.is_empty() {
+            self.append(&mut other);
+            return self;
+        }
+        // the nodes of both lists are handed over to the guard before compare is first called, and
+        // back to this list once merged, or on a panic of compare midway, for the values to be
+        // dropped along with it, rather than freed twice by the drops of two lists linked into one
+        // another by then
+        {
+            let mut nodes = RelinkOnDrop::new(&mut self);
+            nodes.len += mem::take(&mut other.len);
+            (nodes.left, nodes.right) = (nodes.unsorted.take(), other.head.take().zip(other.tail.take()));
+            // SAFETY: the guard holds all of the nodes of both lists, the one borrowed mutably and the
+            // other emptied
+            nodes.merged = Some(unsafe { nodes.merge(&mut compare) });
+        }
+        self
+    }
+
+    // drops every value after the first len ones, keeping the list as is if it's no longer than that
+    fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            drop(self.split_off(len));
+        }
+    }
+
+    /// splits the list in two at the index, leaving the values before it in the list, and returning
+    /// the ones from there on as a list of their own, made of the very same nodes, in O(at) for the
+    /// walk to the split
+    ///
+    /// # Panics
+    ///
+    /// if the index is greater than the len, like std::collections::LinkedList::split_off
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        assert!(at <= self.len, "cannot split off at a nonexistent index (is {at}, len is {})", self.len);
+        if at == 0 {
+            return mem::take(self);
+        }
+        let new_tail = self.node_at(at - 1);
+        // SAFETY: see peek, with the list borrowed mutably, where the nodes after the new tail are
+        // unlinked from it for the new list to be their one owner
+        let suffix_head = unsafe { (*new_tail.as_ptr()).next.take() };
+        let suffix = LinkedList {
+            head: suffix_head,
+            tail: suffix_head.and(self.tail),
+            len: self.len - at,
+            owns_nodes: PhantomData,
+        };
+        self.tail = Some(new_tail);
+        self.len = at;
+        suffix
+    }
+
+    /// puts the value at the index, shifting the values from there on back by one, where an index of
+    /// len puts it at the back
+    ///
+    /// # Panics
+    ///
+    /// if the index is greater than the len, like Vec::insert
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len, "insertion index (is {index}) should be <= len (is {})", self.len);
+        if index == 0 {
+            return self.push_front(value);
+        }
+        if index == self.len {
+            return self.push_back(value);
+        }
+        let before = self.node_at(index - 1);
+        // SAFETY: see peek, with the list borrowed mutably, where the node before has one after it,
+        // the index being short of the len
+        unsafe {
+            let new_node = Self::alloc_node(value, (*before.as_ptr()).next);
+            (*before.as_ptr()).next = Some(new_node);
+        }
+        self.len += 1;
+    }
+
+    /// takes the value at the index out of the list, shifting the values after it forward by one, or
+    /// None for an index out of the list, like VecDeque::remove
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        if index == 0 {
+            return self.pop_front();
+        }
+        let before = self.node_at(index - 1);
+        // SAFETY: see peek and pop_front, where the node removed is the one after the node before,
+        // unlinked from it before being freed
+        let removed = unsafe {
+            let removed = Box::from_raw((*before.as_ptr()).next.unwrap().as_ptr());
+            (*before.as_ptr()).next = removed.next;
+            removed
+        };
+        if removed.next.is_none() {
+            self.tail = Some(before);
+        }
+        self.len -= 1;
+        Some(removed.data)
+    }
+
+    /// the value at the index, or None for an index out of the list, by a walk from the head in O(n),
+    /// unlike the O(1) of indexing a Vec
+    pub fn get(&self, index: usize) -> Option<&T> {
+        // SAFETY: see peek
+        (index < self.len).then(|| unsafe { &(*self.node_at(index).as_ptr()).data })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        // SAFETY: see peek_mut
+        (index < self.len).then(|| unsafe { &mut (*self.node_at(index).as_ptr()).data })
+    }
+
+    // the node at the index, short of the len, by a walk from the head
+    fn node_at(&self, index: usize) -> NonNull<Node<T>> {
+        debug_assert!(index < self.len);
+        let mut node = self.head.unwrap();
+        for _ in 0..index {
+            // SAFETY: see peek, where the walk takes no more steps than there are nodes after the head
+            node = unsafe { (*node.as_ptr()).next.unwrap() };
+        }
+        node
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // the one place where the nodes come to be, allocated as Boxes to be freed as such by pop_front
+    fn alloc_node(data: T, next: Link<T>) -> NonNull<Node<T>> {
+        NonNull::from(Box::leak(Box::new(Node { data, next })))
+    }
+
     /// impl public-facing APIs that adapt LinkList into Iterators
     /// following the convention of such utitily APIs provided by common collections in std
     /// three flavors of Iterators is usually provided to callers
     /// - an Iterator yielding owned T, provided an owned instanced of LinkedList<T>
     /// - an Iterator yielding &T, provided a &LinkedList<T>
     /// - an Iteraotr yield &mut T, provided a &mut LinkList<T>
+    ///
     /// By providing the implementation of the IntoIterator trait for LinkedList<T>, along with its required backing type
     /// that implements Iterator<Item=T>, the first sort of the tool expected by the callers is delivered by
     /// using the into_iter public interface
     /// Hence what's left is to provide the other two public interfaces and give their backing implementations
-    pub fn iter(&self) -> LinkedListIter<T> {
+    pub fn iter(&self) -> LinkedListIter<'_, T> {
         LinkedListIter {
-            next_item: &self.head,
+            // SAFETY: see peek
+            next_item: self.head.map(|head| unsafe { &*head.as_ptr() }),
         }
     }
 
-    // pub fn iter_mut<'a, 'b>(&'a mut self) -> LinkedListIterMut<'b, T> 
-    // where 'a: 'b
-    // {
-    //     LinkedListIterMut {
-    //         next_item: &mut self.head
-    //     }
-    // }
-
-    pub fn iter_mut<'a>(&'a mut self) -> LinkedListIterMut<'a, T> 
-    {   
+    pub fn iter_mut(&mut self) -> LinkedListIterMut<'_, T> {
         LinkedListIterMut {
-            // next_item: &mut self.head,
-            next_item: self.head.as_mut() // Option<&mut Box<Node<T>>>
-            // next_item: self.head.as_deref_mut() // Option<&mut Node<T>>
+            // SAFETY: see peek, with the list borrowed mutably
+            next_item: self.head.map(|head| unsafe { &mut *head.as_ptr() }),
+        }
+    }
+
+    /// a cursor at the first value, to move along the list and edit it where it points, or at the
+    /// ghost position past the back for an empty list
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { current: self.head, prev: None, index: 0, list: self }
+    }
+
+}
+
+// a run of linked nodes, by its first and its last node, the last linking to nothing
+type Run<T> = (NonNull<Node<T>>, NonNull<Node<T>>);
+
+// the nodes of a list being sorted, or of two lists being merged into the one, held in runs that are
+// each linked up on their own whenever compare is called, s.t. a panic of it midway leaves every node
+// in one of them, for the drop to link them all back into the list, as [T]::sort_by leaves every
+// value in the slice on such a panic. with no panic, the one run left at the end is the sorted list
+struct RelinkOnDrop<'a, T> {
+    list: &'a mut LinkedList<T>,
+    len: usize,
+    // the nodes the sort hasn't reached yet, in the order of the list
+    unsorted: Option<Run<T>>,
+    // the sorted runs, of 2^i nodes in the i-th slot, whose nodes all come before the ones of the
+    // slots below it in the list
+    sorted: [Option<Run<T>>; usize::BITS as usize],
+    // the two runs being merged, and the run merged of them so far
+    left: Option<Run<T>>,
+    right: Option<Run<T>>,
+    merged: Option<Run<T>>,
+}
+
+impl<'a, T> RelinkOnDrop<'a, T> {
+    // takes the nodes over from the list, which is left empty until the guard is dropped
+    fn new(list: &'a mut LinkedList<T>) -> Self {
+        let len = mem::take(&mut list.len);
+        let unsorted = list.head.take().zip(list.tail.take());
+        RelinkOnDrop { list, len, unsorted, sorted: [None; usize::BITS as usize], left: None, right: None, merged: None }
+    }
+
+    // sorts the nodes one at a time, each merged with the sorted runs of 1, 2, 4, ... nodes in the
+    // slots for as long as those are taken, the way a binary counter carries a 1 over, with the runs
+    // left in the slots at the end merged from the smallest on
+    //
+    // SAFETY: the caller guarantees that the runs are made of live nodes the list owns, which no one
+    // else touches while they're being relinked
+    unsafe fn sort<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: &mut F) {
+        while let Some((node, last)) = self.unsorted {
+            self.unsorted = (*node.as_ptr()).next.take().map(|next| (next, last));
+            let mut carry = (node, node);
+            let mut slot = 0;
+            while let Some(run) = self.sorted[slot].take() {
+                (self.left, self.right) = (Some(run), Some(carry));
+                carry = self.merge(compare);
+                slot += 1;
+            }
+            self.sorted[slot] = Some(carry);
+        }
+        for slot in 0..self.sorted.len() {
+            if let Some(run) = self.sorted[slot].take() {
+                // the nodes merged so far come after the ones of the run, going right
+                self.right = self.merged.take();
+                self.left = Some(run);
+                self.merged = Some(self.merge(compare));
+            }
+        }
+    }
+
+    // merges the left run with the right one by relinking their nodes one after another, where the
+    // node of the left run goes first unless the one of the right run is strictly less, for equal
+    // values to keep their order. a node is unlinked from its run before it's linked to the merged
+    // one, s.t. the three runs are each linked up on their own whenever compare is called. returns
+    // the merged run, with none of the three left in the guard
+    //
+    // SAFETY: see sort
+    unsafe fn merge<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: &mut F) -> Run<T> {
+        while let (Some((left, left_last)), Some((right, right_last))) = (self.left, self.right) {
+            let (node, run, last) = if compare(&(*right.as_ptr()).data, &(*left.as_ptr()).data) == Ordering::Less {
+                (right, &mut self.right, right_last)
+            } else {
+                (left, &mut self.left, left_last)
+            };
+            *run = (*node.as_ptr()).next.take().map(|next| (next, last));
+            self.merged = Self::concat(self.merged, Some((node, node)));
+        }
+        // what's left of either run is sorted and in place already, to be linked as a whole
+        let rest = self.left.take().or(self.right.take());
+        Self::concat(self.merged.take(), rest).unwrap()
+    }
+
+    // links the second run after the first one
+    //
+    // SAFETY: see sort
+    unsafe fn concat(first: Option<Run<T>>, second: Option<Run<T>>) -> Option<Run<T>> {
+        match (first, second) {
+            (Some((head, last)), Some((second_head, second_last))) => {
+                (*last.as_ptr()).next = Some(second_head);
+                Some((head, second_last))
+            },
+            (first, second) => first.or(second),
         }
     }
+}
 
+impl<T> Drop for RelinkOnDrop<'_, T> {
+    fn drop(&mut self) {
+        let runs = [self.merged.take(), self.left.take(), self.right.take()]
+            .into_iter()
+            .chain(self.sorted.iter_mut().rev().map(Option::take))
+            .chain([self.unsorted.take()]);
+        // SAFETY: every node the list owns is in exactly one of the runs, to be linked into the list
+        // once, whether it's all sorted by now or not
+        let nodes = runs.fold(None, |nodes, run| unsafe { Self::concat(nodes, run) });
+        self.list.head = nodes.map(|(head, _)| head);
+        self.list.tail = nodes.map(|(_, last)| last);
+        self.list.len = self.len;
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        // pushed at the back one by one, for the copy to keep the order of the original
+        self.iter().cloned().collect()
+    }
+
+    /// the nodes the list already has are reused for the values of the source, cloned into them in
+    /// place, where only the nodes short of the source are allocated, or the ones beyond it freed
+    fn clone_from(&mut self, source: &Self) {
+        let mut source_values = source.iter();
+        let mut reused = 0;
+        for value in self.iter_mut() {
+            match source_values.next() {
+                Some(source_value) => value.clone_from(source_value),
+                None => break,
+            }
+            reused += 1;
+        }
+        self.truncate(reused);
+        self.extend(source_values.cloned());
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// list[index] for get, in O(n) all the same
+///
+/// # Panics
+///
+/// if the index is out of the list, like indexing a Vec
+impl<T> Index<usize> for LinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        let len = self.len;
+        self.get(index).unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {index}"))
+    }
+}
+
+impl<T> IndexMut<usize> for LinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(index).unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {index}"))
+    }
+}
+
+/// two lists compare the way their values do, one by one from the head, just like slices do
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // the cached lens tell lists of different sizes apart without a walk
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // the len goes first, as it does for slices, for e.g. a list of lists not to hash the same
+        // as the one list of all their values
+        state.write_usize(self.len);
+        self.iter().for_each(|value| value.hash(state));
+    }
+}
+
+/// the list goes by a sequence of its values, the way a Vec of them does, s.t. the two are
+/// interchangeable in serialized data, e.g. a JSON array
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for LinkedList<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for LinkedList<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SeqVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for SeqVisitor<T> {
+            type Value = LinkedList<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            // the values are pushed at the back as they come, for the list to keep their order
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<LinkedList<T>, A::Error> {
+                let mut list = LinkedList::new();
+                while let Some(value) = seq.next_element()? {
+                    list.push_back(value);
+                }
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor(PhantomData))
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        // the nodes are owned by raw pointers, which drop nothing on their own. popping them one at a
+        // time also keeps the drop of a long list off the stack: freed the way the Boxes of the links
+        // used to drop them, the head would have freed the node after it before returning, and so on,
+        // a frame of the stack per node, to overflow the stack of the thread for a list long enough
+        while self.pop_front().is_some() {}
+    }
 }
 
 /// backing impl for providing Iterator<Item = &'a T>, given &'a LinkList<T>
 pub struct LinkedListIter<'a, T> {
-    // provided &'a LinkedList<T>, it is ok to have &'a Link<T> extracted from
-    // the head field of that, and sticks it in the LinkedListIter<'a, T>
-    next_item: &'a Link<T>
+    // provided &'a LinkedList<T>, it is ok to have &'a Node<T> to any of its nodes, and stick the one
+    // to the next node in the LinkedListIter<'a, T>
+    next_item: Option<&'a Node<T>>
 }
 
 impl<'a, T> Iterator for LinkedListIter<'a, T> {
@@ -148,83 +742,238 @@ impl<'a, T> Iterator for LinkedListIter<'a, T> {
         /// the implementation of the API needs to fulfill two requirements, which is to
         /// first and foremost, return a reference to the data item, &T, to the caller,
         /// and on the otherhand, update the instance to have the reference to the "next"
-        /// link, to be ready for the subsequent calls
-        match *self.next_item {
-            None => {
-                // in case of being pointing to the end of the LinkedList
-                return None;
-            },
-            Some(ref ref_to_boxed_node) => {
-                let next_link: &'a Link<T> = &self.next_item.as_ref().unwrap().next;
-                self.next_item = next_link;
-                return Some(&ref_to_boxed_node.data);
-            }
-        }
+        /// node, to be ready for the subsequent calls
+        self.next_item.map(|node| {
+            // SAFETY: the node after is owned by the same list, borrowed for 'a
+            self.next_item = node.next.map(|next| unsafe { &*next.as_ptr() });
+            &node.data
+        })
     }
 }
 
 /// backing impl for providing Iterator<Item = &'a mut T>, given &'a mut LinkList<T>
-/// probably a naive solution by blindly following the implementation given for Iter 
-/// that if implemented as is, would imply that with the given
-/// &'a mut LinkList<T> and call the next method provided on the "would be" provided
-/// Iterator<Item = 'a mut T>, the caller "would have" obtained potentially more than one
-/// &'a mut T, which would be in violation of the borrowing rule of safe Rust 
-// pub struct LinkedListIterMut<'a, T> {
-//     next_item: &'a mut Link<T>,
-// }
-
-// impl<'a, T> Iterator for LinkedListIterMut<'a, T> {
-//     type Item = &'a mut T;
-
-//     fn next(&mut self) -> Option<Self::Item> {
-//         match *self.next_item {
-//             None => {
-//                 return None;
-//             },
-//             Some(ref mut ref_to_boxed_node) => {
-//                 self.next_item = &mut ref_to_boxed_node.next;
-//                 return Some(&mut ref_to_boxed_node.data);
-//             }
-//         }
-//     }
-// }
-
 pub struct LinkedListIterMut<'a, T> {
-    // next_item: &'a mut Option<Box<Node<T>>>,
-    next_item: Option<&'a mut Box<Node<T>>>,
-    // next_item: Option<&'a mut Node<T>>,
+    // unlike the shared &'a Node<T> of LinkedListIter, a &'a mut Node<T> can't be copied out of the
+    // iterator, only reborrowed for the shorter lifetime of the &mut self of next, which is why the
+    // &'a mut to the next node is held in an Option, to be moved out of it by take()
+    next_item: Option<&'a mut Node<T>>,
 }
 
-impl <'a, T> Iterator for LinkedListIterMut<'a, T> {
+impl<'a, T> Iterator for LinkedListIterMut<'a, T> {
     type Item = &'a mut T;
 
-    fn next<'c>(self: &'c mut LinkedListIterMut<'a, T>) -> Option<&'a mut T>
-    {
-        // match self.next_item {
-        //     None => {},
-        //     Some(ref mut v) => {
-        //         // self.next_item = &mut v.next;
-
-        //         // self.next.take().map(|node| {
-        //         //     self.next = node.next.as_deref_mut();
-        //         //     &mut node.elem
-        //         // })
-        //     }
-        // }
-        if self.next_item.is_none() {
-
-        } else {
-            // return Some(&mut self.next_item.as_mut().unwrap().data);
-            
-            let _t: Option<&'a mut Box<Node<T>>> = self.next_item.take();
-            // return Some(&mut self.next_item.take().unwrap().data);
+    fn next(&mut self) -> Option<&'a mut T> {
+        /// taking the &'a mut Node<T> out of the iterator hands over the whole of the node for 'a,
+        /// to be split into the &'a mut to its data, returned to the caller, and the &'a mut to
+        /// the node after it, put back into the iterator, neither of which overlaps with the other
+        self.next_item.take().map(|node| {
+            // SAFETY: the node after is owned by the same list, borrowed mutably for 'a, and none
+            // of the &mut handed out so far is to it
+            self.next_item = node.next.map(|next| unsafe { &mut *next.as_ptr() });
+            &mut node.data
+        })
+    }
+}
+
+
+
+/// a position in the list to read, modify, insert and remove values at in O(1), once walked to, like
+/// the cursors of std::collections::LinkedList, where the list has a ghost position past the back,
+/// from which the cursor wraps around to the front. the list being singly linked, the cursor moves
+/// forwards only, and keeps a pointer to the node before its current one, for the node to be
+/// unlinked from it, or a new node linked in between
+pub struct CursorMut<'a, T> {
+    list: &'a mut LinkedList<T>,
+    // None at the ghost position
+    current: Link<T>,
+    // the node before the current one, None at the front, and the tail at the ghost position
+    prev: Link<T>,
+    // the number of nodes before the current one, the len at the ghost position
+    index: usize,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// the index of the current value, None at the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        // SAFETY: every link the cursor holds points to a live node of the list, which is borrowed
+        // mutably by the cursor, borrowed mutably in turn for as long as the reference lives
+        self.current.map(|current| unsafe { &mut (*current.as_ptr()).data })
+    }
+
+    /// the value the cursor would move to next, the front one at the ghost position
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.current {
+            // SAFETY: see current
+            Some(current) => unsafe { (*current.as_ptr()).next },
+            None => self.list.head,
+        };
+        // SAFETY: see current
+        next.map(|next| unsafe { &mut (*next.as_ptr()).data })
+    }
+
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(current) => {
+                self.prev = Some(current);
+                // SAFETY: see current
+                self.current = unsafe { (*current.as_ptr()).next };
+                self.index += 1;
+            },
+            None => {
+                self.prev = None;
+                self.current = self.list.head;
+                self.index = 0;
+            },
         }
+    }
 
-        None
+    /// puts the value before the current one, or at the back at the ghost position, the cursor
+    /// staying on the current value
+    pub fn insert_before(&mut self, value: T) {
+        let Some(current) = self.current else {
+            self.list.push_back(value);
+            self.prev = self.list.tail;
+            self.index += 1;
+            return;
+        };
+        let new_node = LinkedList::alloc_node(value, Some(current));
+        match self.prev {
+            // SAFETY: see current
+            Some(prev) => unsafe { (*prev.as_ptr()).next = Some(new_node) },
+            None => self.list.head = Some(new_node),
+        }
+        self.prev = Some(new_node);
+        self.index += 1;
+        self.list.len += 1;
+    }
+
+    /// puts the value after the current one, or at the front at the ghost position, the cursor
+    /// staying on the current value
+    pub fn insert_after(&mut self, value: T) {
+        let Some(current) = self.current else {
+            self.list.push_front(value);
+            // the new front node is the tail as well, had the list been empty
+            self.prev = self.list.tail;
+            self.index += 1;
+            return;
+        };
+        // SAFETY: see current
+        unsafe {
+            let new_node = LinkedList::alloc_node(value, (*current.as_ptr()).next);
+            (*current.as_ptr()).next = Some(new_node);
+            if self.list.tail == Some(current) {
+                self.list.tail = Some(new_node);
+            }
+        }
+        self.list.len += 1;
+    }
+
+    /// puts all the values of the other list before the current one, or at the back at the ghost
+    /// position, by linking the ends of the other list in, in O(1), the cursor staying on the current
+    /// value
+    pub fn splice_before(&mut self, mut other: LinkedList<T>) {
+        let (Some(other_head), Some(other_tail)) = (other.head, other.tail) else {
+            return;
+        };
+        let other_len = other.len;
+        if self.current.is_none() {
+            self.list.append(&mut other);
+            self.prev = self.list.tail;
+            self.index += other_len;
+            return;
+        }
+        match self.prev {
+            // SAFETY: see current, where the nodes of the other list are taken over by this one
+            Some(prev) => unsafe { (*prev.as_ptr()).next = Some(other_head) },
+            None => self.list.head = Some(other_head),
+        }
+        // SAFETY: see current
+        unsafe { (*other_tail.as_ptr()).next = self.current };
+        self.prev = Some(other_tail);
+        self.index += other_len;
+        self.list.len += other_len;
+        // the husk of the other list owns no node anymore, which its drop would otherwise free
+        mem::forget(other);
+    }
+
+    /// puts all the values of the other list after the current one, or at the front at the ghost
+    /// position, in O(1), the cursor staying on the current value
+    pub fn splice_after(&mut self, other: LinkedList<T>) {
+        let (Some(other_head), Some(other_tail)) = (other.head, other.tail) else {
+            return;
+        };
+        let other_len = other.len;
+        match self.current {
+            // SAFETY: see current, where the nodes of the other list are taken over by this one
+            Some(current) => unsafe {
+                (*other_tail.as_ptr()).next = (*current.as_ptr()).next;
+                (*current.as_ptr()).next = Some(other_head);
+                if self.list.tail == Some(current) {
+                    self.list.tail = Some(other_tail);
+                }
+            },
+            None => {
+                // SAFETY: see current
+                unsafe { (*other_tail.as_ptr()).next = self.list.head };
+                self.list.head = Some(other_head);
+                if self.list.tail.is_none() {
+                    self.list.tail = Some(other_tail);
+                }
+                // the values spliced in at the front are before the ghost position as well
+                self.prev = self.list.tail;
+                self.index += other_len;
+            },
+        }
+        self.list.len += other_len;
+        // see splice_before
+        mem::forget(other);
+    }
+
+    /// takes the current value out of the list, the cursor moving on to the value after it, or to the
+    /// ghost position past the removed back, and None at the ghost position
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current?;
+        // SAFETY: see current and LinkedList::pop_front, where the node is unlinked from the node
+        // before it, or the head, before being freed
+        let removed = unsafe { Box::from_raw(current.as_ptr()) };
+        match self.prev {
+            // SAFETY: see current
+            Some(prev) => unsafe { (*prev.as_ptr()).next = removed.next },
+            None => self.list.head = removed.next,
+        }
+        if removed.next.is_none() {
+            self.list.tail = self.prev;
+        }
+        self.current = removed.next;
+        self.list.len -= 1;
+        Some(removed.data)
     }
 }
 
+/// backing impl for extract_if, walking the list by a cursor that removes the values extracted, and
+/// moves past the others
+pub struct ExtractIf<'a, T, F> {
+    cursor: CursorMut<'a, T>,
+    extract: F,
+}
 
+impl<T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'_, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(value) = self.cursor.current() {
+            if (self.extract)(value) {
+                return self.cursor.remove_current();
+            }
+            self.cursor.move_next();
+        }
+        None
+    }
+}
 
 /// backing impl for providing Iterator<Item = T>, given owned LinkList<T>
 pub struct LinkedListIntoIter<T> {
@@ -251,4 +1000,708 @@ impl<T> IntoIterator for LinkedList<T> {
     }
 }
 
+/// the values end up in the list in the order the iterator yields them, each pushed at the back,
+/// rather than pushed at the front, which would reverse them
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+/// the values go after the last node one by one, by the pointer to the tail, in the order the
+/// iterator yields them
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> From<LinkedList<T>> for Vec<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        let mut vec = Vec::with_capacity(list.len());
+        vec.extend(list);
+        vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn push_and_pop_at_both_ends() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.back(), None);
+        assert_eq!(list.pop_back(), None);
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!((list.peek(), list.back()), (Some(&1), Some(&3)));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.back(), Some(&2));
+        // the last node is written through the link of the node before it in the meantime
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+        list.push_back(4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 20, 4]);
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_front(), Some(20));
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.back(), None);
+        // the emptied list starts over at both ends alike
+        list.push_back(5);
+        assert_eq!((list.peek(), list.back()), (Some(&5), Some(&5)));
+        assert_eq!(list.pop_front(), Some(5));
+        list.push_front(6);
+        assert_eq!(list.back(), Some(&6));
+    }
+
+    #[test]
+    fn len_follows_every_push_and_pop() {
+        let mut list = LinkedList::new();
+        assert!(list.is_empty());
+        list.push_front(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!((list.len(), list.is_empty()), (3, false));
+        list.pop_back();
+        assert_eq!(list.len(), 2);
+        list.pop_front();
+        list.pop_front();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!((list.len(), list.is_empty()), (0, true));
+    }
+
+    #[test]
+    // far too slow to run node by node under miri, with nothing unsafe to it that the other tests
+    // don't already cover
+    #[cfg_attr(miri, ignore)]
+    fn drop_long_list_without_overflowing_stack() {
+        // on a thread of its own, whose small stack a recursive drop would overflow well before the
+        // end of the list
+        std::thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let mut list = LinkedList::new();
+                for value in 0..500_000 {
+                    list.push_front(value);
+                }
+                assert_eq!(list.len(), 500_000);
+                drop(list);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn collect_and_round_trip_through_vec() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        assert_eq!((list.peek(), list.back(), list.len()), (Some(&1), Some(&3), 3));
+        assert_eq!(Vec::from(list), vec![1, 2, 3]);
+        let list = LinkedList::from(vec![String::from("a"), String::from("b")]);
+        assert_eq!(list.iter().map(String::as_str).collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(Vec::from(LinkedList::<u8>::from(vec![])), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn extend_after_the_last_node() {
+        let mut list = LinkedList::new();
+        list.extend(Vec::<i32>::new());
+        assert!(list.is_empty() && list.back().is_none());
+        list.extend(vec![1, 2]);
+        list.extend(&[3, 4]);
+        list.push_back(5);
+        assert_eq!((list.len(), list.back()), (5, Some(&5)));
+        assert_eq!(Vec::from(list), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn clone_keeps_order_and_clone_from_reuses_nodes() {
+        let list: LinkedList<String> = ["a", "b", "c"].map(String::from).into_iter().collect();
+        let copy = list.clone();
+        assert_eq!(Vec::from(copy), vec!["a", "b", "c"]);
+
+        let mut longer: LinkedList<String> = ["w", "x", "y", "z"].map(String::from).into_iter().collect();
+        let reused_head = longer.head;
+        longer.clone_from(&list);
+        assert_eq!((longer.head, longer.len(), longer.back()), (reused_head, 3, Some(&String::from("c"))));
+        assert_eq!(Vec::from(longer), vec!["a", "b", "c"]);
+        let mut shorter: LinkedList<String> = std::iter::once(String::from("v")).collect();
+        shorter.clone_from(&list);
+        assert_eq!((shorter.len(), shorter.back()), (3, Some(&String::from("c"))));
+        shorter.push_back(String::from("d"));
+        assert_eq!(Vec::from(shorter), vec!["a", "b", "c", "d"]);
+        let mut emptied = list.clone();
+        emptied.clone_from(&LinkedList::new());
+        assert!(emptied.is_empty() && emptied.back().is_none());
+    }
+
+    #[test]
+    fn debug_like_a_vec() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        assert_eq!(format!("{list:?}"), "[1, 2, 3]");
+        assert_eq!(format!("{:?}", LinkedList::<i32>::new()), "[]");
+        let list: LinkedList<&str> = vec!["a"].into();
+        assert_eq!(format!("{list:#?}"), "[\n    \"a\",\n]");
+    }
 
+    #[test]
+    fn compare_and_hash_value_by_value() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let list = |values: &[i32]| values.iter().copied().collect::<LinkedList<i32>>();
+        assert_eq!(list(&[1, 2]), list(&[1, 2]));
+        assert_ne!(list(&[1, 2]), list(&[1, 2, 3]));
+        assert!(list(&[1, 2]) < list(&[1, 2, 3]) && list(&[1, 3]) > list(&[1, 2, 3]));
+        assert!(list(&[]) < list(&[0]));
+        assert_eq!(list(&[2]).partial_cmp(&list(&[2])), Some(Ordering::Equal));
+
+        let hashed: HashSet<LinkedList<i32>> = [list(&[1, 2]), list(&[1, 2]), list(&[2, 1])].into_iter().collect();
+        assert_eq!(hashed.len(), 2);
+        let ordered: BTreeSet<LinkedList<i32>> = [list(&[2]), list(&[1, 5]), list(&[1])].into_iter().collect();
+        assert_eq!(ordered.into_iter().collect::<Vec<_>>(), vec![list(&[1]), list(&[1, 5]), list(&[2])]);
+    }
+
+    #[test]
+    fn insert_and_remove_at_every_position() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.remove(0), None);
+        list.insert(0, 2);
+        list.insert(0, 1);
+        list.insert(2, 4);
+        list.insert(2, 3);
+        assert_eq!((list.len(), list.back()), (4, Some(&4)));
+        assert_eq!(Vec::from(list.clone()), vec![1, 2, 3, 4]);
+        assert_eq!(list.remove(4), None);
+        assert_eq!(list.remove(3), Some(4));
+        // the tail moves back onto the node before the one removed
+        assert_eq!(list.back(), Some(&3));
+        list.push_back(5);
+        assert_eq!(list.remove(1), Some(2));
+        assert_eq!(list.remove(0), Some(1));
+        assert_eq!(Vec::from(list.clone()), vec![3, 5]);
+        assert_eq!((list.remove(1), list.remove(0), list.back()), (Some(5), Some(3), None));
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+    fn insert_past_the_back_panics() {
+        let mut list: LinkedList<i32> = vec![1].into();
+        list.insert(2, 3);
+    }
+
+    #[test]
+    fn split_off_hands_over_the_nodes() {
+        let mut list: LinkedList<i32> = (1..=5).collect();
+        let fourth = list.node_at(3);
+        let mut suffix = list.split_off(3);
+        assert_eq!((list.len(), list.back(), suffix.len(), suffix.head), (3, Some(&3), 2, Some(fourth)));
+        assert!(list.split_off(3).is_empty());
+        suffix.push_back(6);
+        assert_eq!(Vec::from(suffix), vec![4, 5, 6]);
+        let whole = list.split_off(0);
+        assert!(list.is_empty() && list.back().is_none());
+        assert_eq!(Vec::from(whole), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn append_moves_the_nodes_of_the_other_list() {
+        let mut list: LinkedList<i32> = (1..=2).collect();
+        let mut other: LinkedList<i32> = (3..=4).collect();
+        let other_tail = other.tail;
+        list.append(&mut other);
+        assert!(other.is_empty() && other.back().is_none());
+        assert_eq!((list.len(), list.tail), (4, other_tail));
+        list.append(&mut other);
+        assert_eq!((list.len(), list.back()), (4, Some(&4)));
+        let mut empty = LinkedList::new();
+        empty.append(&mut list);
+        empty.push_back(5);
+        assert_eq!(Vec::from(empty), vec![1, 2, 3, 4, 5]);
+        other.push_back(6);
+        assert_eq!(Vec::from(other), vec![6]);
+    }
+
+    #[test]
+    fn reverse_swaps_the_ends() {
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        list.reverse();
+        assert_eq!((list.peek(), list.back(), list.len()), (Some(&3), Some(&1), 3));
+        list.push_back(0);
+        assert_eq!(Vec::from(list), vec![3, 2, 1, 0]);
+        let mut empty = LinkedList::<i32>::new();
+        empty.reverse();
+        assert!(empty.peek().is_none() && empty.back().is_none());
+    }
+
+    proptest! {
+        #[test]
+        fn reverse_twice_is_identity(values in prop::collection::vec(any::<i32>(), 0..64)) {
+            let mut list = LinkedList::from(values);
+            let original = list.clone();
+            list.reverse();
+            list.reverse();
+            prop_assert_eq!(list, original);
+        }
+
+        #[test]
+        fn reverse_matches_vec_reverse(mut values in prop::collection::vec(any::<i32>(), 0..64)) {
+            let mut list = LinkedList::from(values.clone());
+            list.reverse();
+            values.reverse();
+            prop_assert_eq!(list.back(), values.last());
+            prop_assert_eq!(Vec::from(list), values);
+        }
+    }
+
+    #[test]
+    fn cursor_edits_where_it_points() {
+        let mut list: LinkedList<i32> = vec![1, 3, 5].into();
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut 1)));
+        assert_eq!(cursor.peek_next(), Some(&mut 3));
+        cursor.insert_before(0);
+        cursor.move_next();
+        cursor.insert_after(4);
+        *cursor.current().unwrap() *= 10;
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.remove_current(), Some(30));
+        assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&mut 4)));
+        cursor.insert_before(2);
+        cursor.move_next();
+        // the back value removed, the cursor ends up at the ghost position
+        assert_eq!(cursor.remove_current(), Some(5));
+        assert_eq!((cursor.index(), cursor.current()), (None, None));
+        assert_eq!(cursor.remove_current(), None);
+        cursor.insert_before(6);
+        cursor.insert_after(-1);
+        assert_eq!(cursor.peek_next(), Some(&mut -1));
+        cursor.move_next();
+        assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut -1)));
+        assert_eq!((list.len(), list.back()), (6, Some(&6)));
+        assert_eq!(Vec::from(list), vec![-1, 0, 1, 2, 4, 6]);
+    }
+
+    #[test]
+    fn cursor_over_empty_list() {
+        let mut list = LinkedList::new();
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!((cursor.index(), cursor.current()), (None, None));
+        assert_eq!(cursor.peek_next(), None);
+        cursor.insert_after(2);
+        cursor.insert_before(3);
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert!(list.is_empty() && list.peek().is_none() && list.back().is_none());
+        list.cursor_front_mut().insert_after(1);
+        assert_eq!((list.peek(), list.back()), (Some(&1), Some(&1)));
+    }
+
+    #[test]
+    fn retain_in_one_pass() {
+        let mut list: LinkedList<i32> = (1..=6).collect();
+        let mut visited = vec![];
+        list.retain(|&value| {
+            visited.push(value);
+            value % 2 == 0
+        });
+        assert_eq!(visited, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!((list.len(), list.peek(), list.back()), (3, Some(&2), Some(&6)));
+        list.retain_mut(|value| {
+            *value += 1;
+            *value < 7
+        });
+        list.push_back(0);
+        assert_eq!(Vec::from(list.clone()), vec![3, 5, 0]);
+        list.retain(|_| false);
+        assert!(list.is_empty() && list.back().is_none());
+    }
+
+    #[test]
+    fn extract_if_takes_matches_lazily() {
+        let mut list: LinkedList<i32> = (1..=8).collect();
+        let evens: Vec<i32> = list.extract_if(|value| *value % 2 == 0).collect();
+        assert_eq!(evens, vec![2, 4, 6, 8]);
+        assert_eq!((list.len(), list.back()), (4, Some(&7)));
+
+        // done after the first match, the values not reached yet stay in the list untouched
+        let mut extract = list.extract_if(|value| {
+            *value *= 10;
+            *value > 20
+        });
+        assert_eq!(extract.next(), Some(30));
+        assert_eq!(Vec::from(list.clone()), vec![10, 5, 7]);
+        assert_eq!(list.extract_if(|_| true).count(), 3);
+        assert!(list.is_empty() && list.back().is_none());
+    }
+
+    #[test]
+    fn sort_relinks_the_nodes() {
+        let mut list: LinkedList<i32> = vec![3, 1, 2].into();
+        let node_of_one = list.node_at(1);
+        list.sort();
+        assert_eq!((list.head, list.back(), list.len()), (Some(node_of_one), Some(&3), 3));
+        list.push_back(0);
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(Vec::from(list), vec![3, 2, 1, 0]);
+        let mut single: LinkedList<i32> = vec![1].into();
+        single.sort();
+        assert_eq!((single.peek(), single.back()), (Some(&1), Some(&1)));
+    }
+
+    proptest! {
+        #[test]
+        fn sort_matches_vec_sort(values in prop::collection::vec(any::<i8>(), 0..64)) {
+            let mut list = LinkedList::from(values.clone());
+            let mut sorted = values;
+            list.sort();
+            sorted.sort();
+            prop_assert_eq!(list.back(), sorted.last());
+            prop_assert_eq!(Vec::from(list), sorted);
+        }
+
+        #[test]
+        fn sort_by_is_stable(values in prop::collection::vec((0..4u8, any::<u16>()), 0..64)) {
+            let mut list = LinkedList::from(values.clone());
+            let mut sorted = values;
+            list.sort_by(|a, b| a.0.cmp(&b.0));
+            sorted.sort_by_key(|pair| pair.0);
+            prop_assert_eq!(Vec::from(list), sorted);
+        }
+    }
+
+    #[test]
+    fn update_both_ends_in_place() {
+        let mut list = LinkedList::new();
+        assert!(list.peek_mut().is_none() && list.back_mut().is_none());
+        list.push_back(1);
+        // the one node is both ends at once
+        *list.front_mut().unwrap() += 1;
+        *list.back_mut().unwrap() *= 10;
+        assert_eq!(list.peek(), Some(&20));
+        list.push_back(3);
+        if let Some(front) = list.peek_mut() {
+            *front = 0;
+        }
+        *list.back_mut().unwrap() += 1;
+        assert_eq!(Vec::from(list), vec![0, 4]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trip_in_order() {
+        let list: LinkedList<String> = vec![String::from("a"), String::from("b")].into();
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, r#"["a","b"]"#);
+        let back: LinkedList<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!((back.len(), back.back()), (2, Some(&String::from("b"))));
+        assert_eq!(back, list);
+        let empty: LinkedList<u8> = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+        assert!(serde_json::from_str::<LinkedList<u8>>("{}").is_err());
+    }
+
+    #[test]
+    fn positional_access() {
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        assert_eq!((list[0], list[2], list.get(1), list.get(3)), (1, 3, Some(&2), None));
+        list[1] *= 10;
+        *list.get_mut(2).unwrap() += 1;
+        assert!(list.get_mut(3).is_none());
+        assert_eq!(Vec::from(list), vec![1, 20, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 1 but the index is 1")]
+    fn index_past_the_back_panics() {
+        let list: LinkedList<i32> = vec![1].into();
+        let _ = list[1];
+    }
+
+    // an op on the list, run against a VecDeque of the same values as the model of what the list should
+    // look like afterwards, where an index is taken modulo the len, or the len plus one for insert, to
+    // hit the list mostly
+    #[derive(Debug, Clone)]
+    enum Op {
+        PushFront(i32),
+        PushBack(i32),
+        PopFront,
+        PopBack,
+        Insert(usize, i32),
+        Remove(usize),
+        Reverse,
+    }
+
+    fn op_strategy() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            any::<i32>().prop_map(Op::PushFront),
+            any::<i32>().prop_map(Op::PushBack),
+            Just(Op::PopFront),
+            Just(Op::PopBack),
+            (any::<usize>(), any::<i32>()).prop_map(|(index, value)| Op::Insert(index, value)),
+            any::<usize>().prop_map(Op::Remove),
+            Just(Op::Reverse),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn behaves_like_vec_deque(ops in prop::collection::vec(op_strategy(), 0..64)) {
+            use std::collections::VecDeque;
+
+            let mut list = LinkedList::new();
+            let mut model = VecDeque::new();
+            for op in ops {
+                match op {
+                    Op::PushFront(value) => {
+                        list.push_front(value);
+                        model.push_front(value);
+                    },
+                    Op::PushBack(value) => {
+                        list.push_back(value);
+                        model.push_back(value);
+                    },
+                    Op::PopFront => prop_assert_eq!(list.pop_front(), model.pop_front()),
+                    Op::PopBack => prop_assert_eq!(list.pop_back(), model.pop_back()),
+                    Op::Insert(index, value) => {
+                        let index = index % (model.len() + 1);
+                        list.insert(index, value);
+                        model.insert(index, value);
+                    },
+                    Op::Remove(index) => {
+                        let index = index % (model.len() + 1);
+                        prop_assert_eq!(list.remove(index), model.remove(index));
+                    },
+                    Op::Reverse => {
+                        list.reverse();
+                        model.make_contiguous().reverse();
+                    },
+                }
+                prop_assert_eq!((list.len(), list.is_empty()), (model.len(), model.is_empty()));
+                prop_assert_eq!((list.peek(), list.back()), (model.front(), model.back()));
+                prop_assert!(list.iter().eq(model.iter()));
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_both_ways() {
+        let mut list: LinkedList<i32> = (1..=5).collect();
+        let first_node = list.head;
+        list.rotate_left(2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5, 1, 2]);
+        assert_eq!((list.node_at(3), list.back(), list.len()), (first_node.unwrap(), Some(&2), 5));
+        list.rotate_right(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![5, 1, 2, 3, 4]);
+        list.rotate_left(0);
+        list.rotate_right(5);
+        list.push_back(6);
+        assert_eq!(Vec::from(list), vec![5, 1, 2, 3, 4, 6]);
+        let mut empty = LinkedList::<i32>::new();
+        empty.rotate_left(0);
+        assert!(empty.is_empty() && empty.back().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot rotate by 2, more than the len 1")]
+    fn rotate_past_the_len_panics() {
+        let mut list: LinkedList<i32> = vec![1].into();
+        list.rotate_left(2);
+    }
+
+    #[test]
+    fn merge_splices_sorted_lists() {
+        let left: LinkedList<(i32, char)> = vec![(1, 'l'), (3, 'l'), (3, 'l'), (8, 'l')].into();
+        let right: LinkedList<(i32, char)> = vec![(0, 'r'), (3, 'r'), (9, 'r')].into();
+        let right_last = right.tail;
+        let mut merged = left.merge(right, |a, b| a.0.cmp(&b.0));
+        assert_eq!((merged.len(), merged.tail), (7, right_last));
+        merged.push_back((10, 'm'));
+        let values: Vec<(i32, char)> = merged.into_iter().collect();
+        assert_eq!(values, vec![(0, 'r'), (1, 'l'), (3, 'l'), (3, 'l'), (3, 'r'), (8, 'l'), (9, 'r'), (10, 'm')]);
+
+        let only_right = LinkedList::new().merge(vec![1, 2].into(), i32::cmp);
+        let only_left = only_right.merge(LinkedList::new(), i32::cmp);
+        assert_eq!((only_left.len(), only_left.back()), (2, Some(&2)));
+    }
+
+    #[test]
+    fn sort_panicking_compare_keeps_every_value() {
+        for panic_at in 1..12 {
+            let mut list: LinkedList<String> = ["e", "b", "g", "a", "f", "c", "d"].map(String::from).into_iter().collect();
+            let mut compare_cnt = 0;
+            let sort_panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.sort_by(|a, b| {
+                    compare_cnt += 1;
+                    assert!(compare_cnt < panic_at, "compare panicking midway");
+                    a.cmp(b)
+                })
+            }));
+            assert!(sort_panic.is_err());
+            // the values are all back in the list, in some order, with the tail and the len to match
+            assert_eq!((list.len(), list.back()), (7, list.iter().last()));
+            let mut values = Vec::from(list);
+            values.sort();
+            assert_eq!(values, ["a", "b", "c", "d", "e", "f", "g"]);
+        }
+    }
+
+    #[test]
+    fn merge_panicking_compare_drops_every_value_once() {
+        let values: Vec<std::rc::Rc<i32>> = [1, 5, 2, 3].map(std::rc::Rc::new).into();
+        let left: LinkedList<_> = values[..2].to_vec().into();
+        let right: LinkedList<_> = values[2..].to_vec().into();
+        let mut compare_cnt = 0;
+        let merge_panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            left.merge(right, |a, b| {
+                compare_cnt += 1;
+                assert!(compare_cnt < 3, "compare panicking midway");
+                a.cmp(b)
+            })
+        }));
+        // by the third comparison, the nodes of both lists are linked into one another, and still
+        // dropped along with the merged list, neither leaked nor freed twice
+        assert!(merge_panic.is_err());
+        assert_eq!(compare_cnt, 3);
+        assert!(values.iter().all(|value| std::rc::Rc::strong_count(value) == 1));
+    }
+
+    #[test]
+    fn cursor_splices_whole_lists() {
+        let mut list: LinkedList<i32> = vec![1, 5].into();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.splice_before(vec![2, 3].into());
+        cursor.splice_after(vec![6, 7].into());
+        cursor.splice_before(LinkedList::new());
+        assert_eq!((cursor.index(), cursor.current()), (Some(3), Some(&mut 5)));
+        cursor.splice_before(vec![4].into());
+        // the spliced tail is the new tail of the list
+        cursor.move_next();
+        cursor.move_next();
+        cursor.splice_after(vec![8].into());
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!((cursor.index(), cursor.current()), (None, None));
+        cursor.splice_before(vec![9].into());
+        cursor.splice_after(vec![-1, 0].into());
+        cursor.move_next();
+        assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut -1)));
+        assert_eq!((list.len(), list.back()), (11, Some(&9)));
+        assert_eq!(Vec::from(list), (-1..=9).collect::<Vec<_>>());
+
+        let mut empty = LinkedList::new();
+        let mut cursor = empty.cursor_front_mut();
+        cursor.splice_after(vec![1, 2].into());
+        cursor.splice_before(vec![3].into());
+        assert_eq!((empty.len(), empty.peek(), empty.back()), (3, Some(&1), Some(&3)));
+    }
+
+    #[test]
+    fn iter_mut_reaches_every_element() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.iter_mut().next(), None);
+        for value in 1..=3 {
+            list.push_front(value);
+        }
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![30, 20, 10]);
+        // the &mut handed out are all alive at the same time, each to an element of its own
+        let mut values: Vec<&mut i32> = list.iter_mut().collect();
+        *values[0] += 1;
+        *values[2] += 3;
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![31, 20, 13]);
+    }
+
+    /// the claim that `Option<NonNull<Node<T>>>` is as cheap as a raw pointer relies on the niche of
+    /// NonNull: it is never null, so the None variant is encoded as the null pointer and no separate
+    /// tag is needed, whatever the element type is. the Box the nodes are allocated as has the same
+    /// niche, for the links to be no larger than they were as `Option<Box<Node<T>>>`
+    fn assert_link_is_pointer_sized<T>() {
+        assert_eq!(size_of::<Link<T>>(), size_of::<*const T>());
+        assert_eq!(size_of::<Link<T>>(), size_of::<Box<Node<T>>>());
+        assert_eq!(size_of::<Link<T>>(), size_of::<Option<Box<Node<T>>>>());
+        // a pointer to the head, one to the tail and the len
+        assert_eq!(size_of::<LinkedList<T>>(), 2 * size_of::<*const T>() + size_of::<usize>());
+    }
+
+    #[test]
+    fn link_null_pointer_optimization() {
+        assert_link_is_pointer_sized::<u8>();
+        assert_link_is_pointer_sized::<i32>();
+        assert_link_is_pointer_sized::<u64>();
+        assert_link_is_pointer_sized::<String>();
+        assert_link_is_pointer_sized::<Vec<u64>>();
+    }
+
+    #[test]
+    fn node_has_no_tag_overhead() {
+        // a node is exactly its data plus one (niche-optimized) link, no discriminant anywhere
+        assert_eq!(size_of::<Node<i32>>(), size_of::<(i32, Link<i32>)>());
+        assert_eq!(size_of::<Node<u64>>(), size_of::<(u64, Link<u64>)>());
+    }
+
+    #[test]
+    fn bad_layout_sizes() {
+        // the Nil variant also hides in the niche of the Box in Cons, so the enum itself is no
+        // bigger than the Cons payload...
+        assert_eq!(size_of::<LinkedListBadLayout>(), size_of::<(i32, Box<LinkedListBadLayout>)>());
+        // ...but that payload is carried inline by value, so the handle to the list is bigger than
+        // the single pointer to the head LinkedList needs, and the terminating Nil has to be heap
+        // allocated as a full sized enum behind the Box of the last Cons
+        assert!(size_of::<LinkedListBadLayout>() > size_of::<Link<i32>>());
+        assert_eq!(size_of::<Link<i32>>(), size_of::<*const i32>());
+    }
+
+    #[test]
+    fn bad_layout_against_option_box_node() {
+        // the handle of the Option<Box<Node>> design is the one pointer, half the size of the bad
+        // layout, which carries a value and a pointer...
+        assert_eq!(size_of::<Option<Box<Node<i32>>>>(), size_of::<*const i32>());
+        assert_eq!(size_of::<LinkedListBadLayout>(), 2 * size_of::<Option<Box<Node<i32>>>>());
+        // ...for heap blocks of the very same size, the one holding a Cons and the other a Node, the
+        // bad layout spending a whole one on the Nil at the end, for the value it keeps in the handle
+        assert_eq!(size_of::<LinkedListBadLayout>(), size_of::<Node<i32>>());
+    }
+
+    #[test]
+    fn bad_layout_works_as_a_stack_like_list() {
+        let mut bad_layout = LinkedListBadLayout::new();
+        let mut list = LinkedList::new();
+        assert!(bad_layout.is_empty() && bad_layout.peek().is_none());
+        for value in 1..=3 {
+            bad_layout.push_front(value);
+            list.push_front(value);
+            assert_eq!(bad_layout.peek(), list.peek());
+        }
+        for _ in 0..4 {
+            assert_eq!(bad_layout.pop_front(), list.pop_front());
+        }
+        assert!(bad_layout.is_empty() && list.is_empty());
+    }
+}
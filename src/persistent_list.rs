@@ -0,0 +1,155 @@
+use std::rc::Rc;
+
+/// the immutable cons list of Scala and the functional languages, which LinkedListBadLayout is the
+/// Rust spelling of, where a list is never changed once built: prepending a value makes a new list
+/// out of a new node and the old list as its tail, which the two lists share from then on
+///
+/// sharing the tail is what takes the Rc over a Box, as the node after the new one belongs to the old
+/// list just as much as it belongs to the new one, and to every other list prepended to the old one
+pub struct List<T> {
+    head: Link<T>,
+}
+
+type Link<T> = Option<Rc<Node<T>>>;
+
+struct Node<T> {
+    data: T,
+    next: Link<T>,
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        List { head: None }
+    }
+
+    /// the list of the value followed by the values of this list, whose nodes are shared rather than
+    /// copied, in O(1)
+    pub fn prepend(&self, value: T) -> List<T> {
+        List {
+            head: Some(Rc::new(Node { data: value, next: self.head.clone() })),
+        }
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.data)
+    }
+
+    /// the list of the values after the head, sharing the nodes of this list, or the empty list for an
+    /// empty list
+    pub fn tail(&self) -> List<T> {
+        List {
+            head: self.head.as_ref().and_then(|node| node.next.clone()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next_item: self.head.as_deref() }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// another handle to the very same nodes, with no value cloned, hence no T: Clone bound
+impl<T> Clone for List<T> {
+    fn clone(&self) -> Self {
+        List { head: self.head.clone() }
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        // the nodes are dropped one at a time, as long as this list is the last one holding them,
+        // rather than by the drop of each node dropping the next one in turn, a frame of the stack per
+        // node. the first node shared with another list ends the walk, the rest of the nodes being
+        // still in use by that list
+        let mut next_node = self.head.take();
+        while let Some(node) = next_node {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => next_node = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+/// backing impl for iter, yielding &T from the head on
+pub struct Iter<'a, T> {
+    next_item: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next_item.map(|node| {
+            self.next_item = node.next.as_deref();
+            &node.data
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prepend_head_and_tail() {
+        let empty = List::new();
+        assert!(empty.head().is_none() && empty.tail().is_empty());
+        let list = empty.prepend(3).prepend(2).prepend(1);
+        assert_eq!(list.head(), Some(&1));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(list.tail().tail().iter().copied().collect::<Vec<_>>(), vec![3]);
+        assert!(list.tail().tail().tail().is_empty());
+        // the old lists are still there as they were
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn lists_share_their_tails() {
+        let shared = List::new().prepend(2).prepend(1);
+        let first = shared.prepend(10);
+        let second = shared.prepend(20);
+        let shared_head = shared.head.as_ref().unwrap();
+        // held by shared itself, and as the tail of first and second
+        assert_eq!(Rc::strong_count(shared_head), 3);
+        assert!(Rc::ptr_eq(first.tail().head.as_ref().unwrap(), second.tail().head.as_ref().unwrap()));
+        assert_eq!(second.iter().copied().collect::<Vec<_>>(), vec![20, 1, 2]);
+        drop(first);
+        assert_eq!(Rc::strong_count(shared.head.as_ref().unwrap()), 2);
+        // dropping the second list frees its own node only, and leaves the shared ones to shared
+        drop(second);
+        assert_eq!(Rc::strong_count(shared.head.as_ref().unwrap()), 1);
+        assert_eq!(shared.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn drop_long_list_without_overflowing_stack() {
+        let mut list = List::new();
+        for value in 0..200_000 {
+            list = list.prepend(value);
+        }
+        let shorter = list.tail();
+        drop(list);
+        assert_eq!(shorter.head(), Some(&199_998));
+    }
+}
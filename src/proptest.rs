@@ -8,7 +8,7 @@ proptest! {
 
     #[test]
     fn string_cat_length(a in ".*", b in ".*") {
-        // let cat = format!({}{}, a, b);
-        // prop_assert_eq!()
+        let cat = format!("{}{}", a, b);
+        prop_assert_eq!(cat.len(), a.len() + b.len())
     }
 }
\ No newline at end of file
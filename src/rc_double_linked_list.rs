@@ -0,0 +1,236 @@
+use std::cell::{Ref, RefCell};
+use std::rc::{Rc, Weak};
+
+/// the safe counterpart of a doubly linked list, where every node is shared by the node before it, or
+/// the head, and the node after it, or the tail, none of which can be the one owner a Box would need.
+/// hence the nodes are reference counted, and mutated through a RefCell, as no Rc hands out &mut
+///
+/// the links forwards hold the nodes alive, while the links backwards are Weak, as an Rc each way
+/// between two nodes would be a cycle keeping both alive for good once the list is dropped
+pub struct LinkedList<T> {
+    head: Link<T>,
+    tail: Link<T>,
+}
+
+type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+
+struct Node<T> {
+    data: T,
+    next: Link<T>,
+    prev: Option<Weak<RefCell<Node<T>>>>,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        LinkedList { head: None, tail: None }
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let new_node = Rc::new(RefCell::new(Node { data: value, next: None, prev: None }));
+        match self.head.take() {
+            Some(old_head) => {
+                old_head.borrow_mut().prev = Some(Rc::downgrade(&new_node));
+                new_node.borrow_mut().next = Some(old_head);
+            },
+            None => self.tail = Some(Rc::clone(&new_node)),
+        }
+        self.head = Some(new_node);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let new_node = Rc::new(RefCell::new(Node { data: value, next: None, prev: None }));
+        match self.tail.take() {
+            Some(old_tail) => {
+                new_node.borrow_mut().prev = Some(Rc::downgrade(&old_tail));
+                old_tail.borrow_mut().next = Some(Rc::clone(&new_node));
+            },
+            None => self.head = Some(Rc::clone(&new_node)),
+        }
+        self.tail = Some(new_node);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|old_head| {
+            match old_head.borrow_mut().next.take() {
+                Some(new_head) => {
+                    new_head.borrow_mut().prev = None;
+                    self.head = Some(new_head);
+                },
+                None => self.tail = None,
+            }
+            Self::into_data(old_head)
+        })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.take().map(|old_tail| {
+            match old_tail.borrow_mut().prev.take().and_then(|prev| prev.upgrade()) {
+                Some(new_tail) => {
+                    new_tail.borrow_mut().next = None;
+                    self.tail = Some(new_tail);
+                },
+                None => self.head = None,
+            }
+            Self::into_data(old_tail)
+        })
+    }
+
+    /// the value at the front, borrowed out of the RefCell of its node for as long as the Ref lives,
+    /// which is all the RefCell can lend, rather than a plain &T
+    pub fn peek_front(&self) -> Option<Ref<'_, T>> {
+        self.head.as_ref().map(|head| Ref::map(head.borrow(), |node| &node.data))
+    }
+
+    pub fn peek_back(&self) -> Option<Ref<'_, T>> {
+        self.tail.as_ref().map(|tail| Ref::map(tail.borrow(), |node| &node.data))
+    }
+
+    // the node just unlinked from both ends of the list and from its neighbours has no other Rc left
+    // pointing to it, which is what lets its data be moved out
+    fn into_data(node: Rc<RefCell<Node<T>>>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(node) => node.into_inner().data,
+            Err(_) => unreachable!("a node popped off the list is still linked"),
+        }
+    }
+}
+
+impl<T: Clone> LinkedList<T> {
+    /// iterator over clones of the values, front to back or back to front. unlike the iter of the
+    /// singly linked list, it can't hand out a &T, there being no way to keep the RefCell of every
+    /// node borrowed for as long as the list is, which is the awkward side of the safe design
+    pub fn iter(&self) -> Iter<T> {
+        Iter {
+            front: self.head.clone(),
+            back: self.tail.clone(),
+        }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        // unlinking one node at a time, rather than leaving the drop of each node to drop the next
+        // one in turn, which would take a frame of the stack per node
+        while self.pop_front().is_some() {}
+    }
+}
+
+/// backing impl for iter, walking from both ends towards the other one
+pub struct Iter<T> {
+    front: Link<T>,
+    back: Link<T>,
+}
+
+impl<T: Clone> Iter<T> {
+    // the value of the node just reached from either end, and whether it's the node last reached from
+    // the other end as well, where the two walks meet and the iteration ends
+    fn take_node(&mut self, node: Rc<RefCell<Node<T>>>, is_front: bool) -> T {
+        let met = match (&self.front, &self.back) {
+            (_, Some(back)) if is_front => Rc::ptr_eq(&node, back),
+            (Some(front), _) if !is_front => Rc::ptr_eq(&node, front),
+            _ => false,
+        };
+        if met {
+            self.front = None;
+            self.back = None;
+        }
+        // bound to a local first, for the Ref of the node to be dropped before the node itself
+        let value = node.borrow().data.clone();
+        value
+    }
+}
+
+impl<T: Clone> Iterator for Iter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let node = self.front.take()?;
+        self.front = node.borrow().next.clone();
+        Some(self.take_node(node, true))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for Iter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        let node = self.back.take()?;
+        self.back = node.borrow().prev.as_ref().and_then(Weak::upgrade);
+        Some(self.take_node(node, false))
+    }
+}
+
+/// backing impl for into_iter, popping the values off either end of the list
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_and_pop_at_both_ends() {
+        let mut list = LinkedList::new();
+        assert!(list.pop_front().is_none() && list.pop_back().is_none());
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!((*list.peek_front().unwrap(), *list.peek_back().unwrap()), (1, 3));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(2));
+        assert!(list.peek_front().is_none() && list.peek_back().is_none());
+        list.push_front(4);
+        assert_eq!(list.pop_back(), Some(4));
+    }
+
+    #[test]
+    fn iter_both_ways_until_the_walks_meet() {
+        let mut list = LinkedList::new();
+        (1..=5).for_each(|value| list.push_back(value));
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1]);
+        let mut iter = list.iter();
+        assert_eq!((iter.next(), iter.next_back(), iter.next(), iter.next_back()), (Some(1), Some(5), Some(2), Some(4)));
+        assert_eq!((iter.next_back(), iter.next()), (Some(3), None));
+        assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn nodes_freed_with_the_list() {
+        let mut list = LinkedList::new();
+        (0..3).for_each(|value| list.push_back(Rc::new(value)));
+        let first = list.iter().next().unwrap();
+        assert_eq!(Rc::strong_count(&first), 2);
+        // the Weak links back don't keep the nodes, nor the values in them, alive
+        drop(list);
+        assert_eq!(Rc::strong_count(&first), 1);
+    }
+}
@@ -0,0 +1,380 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// a doubly linked deque the way std::collections::LinkedList does it, where the nodes are owned by
+/// raw pointers each way, with none of the reference counting nor the runtime borrow checks the
+/// Rc<RefCell> list pays for, and the soundness argued for in the SAFETY comments instead
+///
+/// every node is allocated as a Box, turned into a NonNull by Box::leak for as long as it's linked,
+/// and turned back into a Box by Box::from_raw the moment it's unlinked. no reference to a node is
+/// ever held across the linking of another one, only the raw pointers, s.t. the &mut handed out by
+/// iter_mut or front_mut never alias a pointer the list writes through
+pub struct LinkedList<T> {
+    front: Link<T>,
+    back: Link<T>,
+    len: usize,
+    /// NonNull<Node<T>> is covariant in T, as a LinkedList<&'static str> should be usable as a
+    /// LinkedList<&'a str>, but says nothing about owning a T, which this marker does, for the drop
+    /// check to know that dropping the list drops values of T
+    owns_values: PhantomData<T>,
+}
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+struct Node<T> {
+    data: T,
+    prev: Link<T>,
+    next: Link<T>,
+}
+
+// the list owns its values just like a Vec<T> does, and is Send and Sync under the same conditions,
+// which the raw pointers opt it out of by default
+unsafe impl<T: Send> Send for LinkedList<T> {}
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        LinkedList { front: None, back: None, len: 0, owns_values: PhantomData }
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let new_node = Self::alloc_node(value, None, self.front);
+        match self.front {
+            // SAFETY: every link of the list points to a live node owned by the list, which is
+            // borrowed mutably, and no reference to a node outlives a method of the list
+            Some(old_front) => unsafe { (*old_front.as_ptr()).prev = Some(new_node) },
+            None => self.back = Some(new_node),
+        }
+        self.front = Some(new_node);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let new_node = Self::alloc_node(value, self.back, None);
+        match self.back {
+            // SAFETY: see push_front
+            Some(old_back) => unsafe { (*old_back.as_ptr()).next = Some(new_node) },
+            None => self.front = Some(new_node),
+        }
+        self.back = Some(new_node);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let old_front = self.front?;
+        // SAFETY: the node was allocated as a Box by alloc_node, and is unlinked right below, which
+        // leaves no other pointer to it
+        let boxed_node = unsafe { Box::from_raw(old_front.as_ptr()) };
+        self.front = boxed_node.next;
+        match self.front {
+            // SAFETY: see push_front
+            Some(new_front) => unsafe { (*new_front.as_ptr()).prev = None },
+            None => self.back = None,
+        }
+        self.len -= 1;
+        Some(boxed_node.data)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let old_back = self.back?;
+        // SAFETY: see pop_front
+        let boxed_node = unsafe { Box::from_raw(old_back.as_ptr()) };
+        self.back = boxed_node.prev;
+        match self.back {
+            // SAFETY: see push_front
+            Some(new_back) => unsafe { (*new_back.as_ptr()).next = None },
+            None => self.front = None,
+        }
+        self.len -= 1;
+        Some(boxed_node.data)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: see push_front, with the list borrowed for as long as the reference lives
+        self.front.map(|front| unsafe { &(*front.as_ptr()).data })
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: see front, with the list borrowed mutably
+        self.front.map(|front| unsafe { &mut (*front.as_ptr()).data })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: see front
+        self.back.map(|back| unsafe { &(*back.as_ptr()).data })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: see front_mut
+        self.back.map(|back| unsafe { &mut (*back.as_ptr()).data })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { front: self.front, back: self.back, len: self.len, list: PhantomData }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { front: self.front, back: self.back, len: self.len, list: PhantomData }
+    }
+
+    // the one place where the nodes come to be, allocated as Boxes to be freed as such when popped
+    fn alloc_node(data: T, prev: Link<T>, next: Link<T>) -> NonNull<Node<T>> {
+        NonNull::from(Box::leak(Box::new(Node { data, prev, next })))
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// backing impl for iter, walking from both ends towards the other one, where the count of values
+/// not yielded yet tells when the two walks meet
+pub struct Iter<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    len: usize,
+    list: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.front.map(|front| {
+            self.len -= 1;
+            // SAFETY: the node is one of the len nodes not yielded yet, owned by the list borrowed for 'a
+            let node = unsafe { &*front.as_ptr() };
+            self.front = node.next;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.back.map(|back| {
+            self.len -= 1;
+            // SAFETY: see next
+            let node = unsafe { &*back.as_ptr() };
+            self.back = node.prev;
+            &node.data
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// backing impl for iter_mut, which hands out a &mut to each value at most once, from whichever end
+/// gets to it first
+pub struct IterMut<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    len: usize,
+    list: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.front.map(|front| {
+            self.len -= 1;
+            // SAFETY: the node is one of the len nodes not yielded yet from either end, owned by the
+            // list borrowed mutably for 'a. only its data is borrowed, its links being read through
+            // the raw pointer, s.t. the &mut handed out covers nothing the next calls read
+            unsafe {
+                self.front = (*front.as_ptr()).next;
+                &mut (*front.as_ptr()).data
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.back.map(|back| {
+            self.len -= 1;
+            // SAFETY: see next
+            unsafe {
+                self.back = (*back.as_ptr()).prev;
+                &mut (*back.as_ptr()).data
+            }
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// backing impl for into_iter, popping the values off either end of the list
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<S: Send + Sync>() {}
+
+    #[test]
+    fn deque_ops_at_both_ends() {
+        assert_send_sync::<LinkedList<String>>();
+        let mut list = LinkedList::new();
+        assert_eq!((list.pop_front(), list.pop_back(), list.len()), (None, None, 0));
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!((list.front(), list.back(), list.len()), (Some(&1), Some(&3), 3));
+        *list.front_mut().unwrap() *= 10;
+        *list.back_mut().unwrap() *= 10;
+        assert_eq!(format!("{list:?}"), "[10, 2, 30]");
+        assert_eq!((list.pop_back(), list.pop_front()), (Some(30), Some(10)));
+        assert_eq!((list.front(), list.back()), (Some(&2), Some(&2)));
+        assert_eq!(list.pop_back(), Some(2));
+        assert!(list.is_empty() && list.front().is_none() && list.back().is_none());
+        list.push_front(4);
+        assert_eq!(list.pop_back(), Some(4));
+    }
+
+    #[test]
+    fn covariant_in_t() {
+        let mut static_list: LinkedList<&'static str> = LinkedList::new();
+        static_list.push_back("static");
+        let local = String::from("local");
+        // compiles only as long as the list is covariant in T, the way the collections of std are
+        let mut local_list: LinkedList<&str> = static_list;
+        local_list.push_back(&local);
+        assert_eq!(local_list.iter().copied().collect::<Vec<_>>(), vec!["static", "local"]);
+    }
+
+    #[test]
+    fn iterators_meet_in_the_middle() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        (1..=5).for_each(|value| list.push_back(value));
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!((iter.next(), iter.next_back(), iter.next()), (Some(&1), Some(&5), Some(&2)));
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![&4, &3]);
+
+        // every &mut alive at once, handed out from both ends
+        let mut iter_mut = list.iter_mut();
+        let (first, last) = (iter_mut.next().unwrap(), iter_mut.next_back().unwrap());
+        let middle: Vec<&mut u32> = iter_mut.collect();
+        *first += 10;
+        *last += 50;
+        middle.into_iter().for_each(|value| *value *= 2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![11, 4, 6, 8, 55]);
+
+        let mut into_iter = list.into_iter();
+        assert_eq!((into_iter.next_back(), into_iter.len()), (Some(55), 4));
+        // the values left are dropped along with the iterator
+    }
+
+    #[test]
+    fn values_dropped_once() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut list = LinkedList::new();
+        (0..4).for_each(|_| list.push_back(Rc::clone(&value)));
+        drop(list.pop_front());
+        assert_eq!(Rc::strong_count(&value), 4);
+        drop(list);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+}